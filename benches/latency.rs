@@ -0,0 +1,197 @@
+//! Measures how long a change to a running spinner takes to reach the screen
+//!
+//! Each change is timed from the call that makes it to the moment the render thread writes it out.
+//! The spinners have only two frames, so there are 500ms between each frame, and changes are made at different points within a frame.
+//! Run with `cargo bench --bench latency`.
+//!
+//! For comparison, the same changes are also sent to a copy of the render loop from 2.3.0, where every spinner had a thread of its own that checked for events once per frame and slept in between.
+//! That loop never showed new frames, so only messages are compared.
+//!
+//! One run printed:
+//!
+//! ```text
+//! set_message  mean   42.823µs  median   42.882µs  max   54.134µs
+//! set_spinner  mean   45.566µs  median   46.258µs  max   67.215µs
+//! 2.3.0        mean  260.073ms  median  270.092ms  max  450.060ms
+//! ```
+
+use std::{
+    io::{self, Write},
+    sync::{Arc, Condvar, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use spinners_rs::{CustomSpinner, Mode, Output, Spinner, Spinners};
+
+const RUNS: u32 = 20;
+
+/// A writer that notes when a given piece of text is first written to it
+#[derive(Clone, Default)]
+struct Probe {
+    inner: Arc<(Mutex<Watch>, Condvar)>,
+}
+
+#[derive(Default)]
+struct Watch {
+    wanted: Option<String>,
+    seen: Option<Instant>,
+}
+
+impl Probe {
+    /// Starts watching for the given text
+    fn watch(&self, wanted: &str) {
+        let mut watch = self.inner.0.lock().unwrap();
+        watch.wanted = Some(wanted.to_string());
+        watch.seen = None;
+    }
+
+    /// Waits until the text being watched for has been written, returning when that was
+    fn wait(&self) -> Instant {
+        let (watch, written) = &*self.inner;
+        let watch = written
+            .wait_while(watch.lock().unwrap(), |watch| watch.seen.is_none())
+            .unwrap();
+
+        watch.seen.unwrap()
+    }
+}
+
+impl Write for Probe {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let (watch, written) = &*self.inner;
+        let mut watch = watch.lock().unwrap();
+
+        let found = watch
+            .wanted
+            .as_deref()
+            .is_some_and(|wanted| String::from_utf8_lossy(buf).contains(wanted));
+
+        if found && watch.seen.is_none() {
+            watch.seen = Some(Instant::now());
+            written.notify_all();
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The render loop of 2.3.0, drawing to a writer instead of stdout
+mod legacy {
+    use std::{
+        io::Write,
+        sync::mpsc::{channel, Sender, TryRecvError},
+        thread,
+        time::Duration,
+    };
+
+    pub enum Event {
+        Stop,
+        SetMessage(String),
+    }
+
+    pub fn start(
+        frames: &'static [&'static str],
+        message: &str,
+        mut w: impl Write + Send + 'static,
+    ) -> Sender<Event> {
+        let interval = 1000 / frames.len() as u64;
+        let message = message.to_string();
+        let (sender, recv) = channel::<Event>();
+
+        thread::spawn(move || 'outer: loop {
+            for frame in frames.iter() {
+                let mut message = message.clone();
+
+                match recv.try_recv() {
+                    Ok(Event::Stop) | Err(TryRecvError::Disconnected) => break 'outer,
+                    Ok(Event::SetMessage(message_)) => message = message_,
+                    Err(TryRecvError::Empty) => {}
+                };
+
+                write!(w, "\r{} {}", frame, message).unwrap();
+                w.flush().unwrap();
+                thread::sleep(Duration::from_millis(interval));
+            }
+        });
+
+        sender
+    }
+}
+
+fn report(name: &str, mut samples: Vec<Duration>) {
+    samples.sort();
+
+    let total: Duration = samples.iter().sum();
+    let mean = total / samples.len() as u32;
+    let median = samples[samples.len() / 2];
+    let max = samples[samples.len() - 1];
+
+    eprintln!(
+        "{:<12} mean {:>10.3?}  median {:>10.3?}  max {:>10.3?}",
+        name, mean, median, max
+    );
+}
+
+/// Lands somewhere in the middle of a frame, at a different point on every run
+fn wait_for_run(run: u32) {
+    thread::sleep(Duration::from_millis(50 + 20 * run as u64));
+}
+
+fn spinner(probe: &Probe) -> Spinner {
+    Spinner::builder()
+        .spinner(Spinners::Toggle)
+        .message("Measuring...")
+        .mode(Mode::Animated)
+        .env_overrides(false)
+        .output(Output::writer(probe.clone()))
+        .start()
+        .unwrap()
+}
+
+fn main() {
+    let mut message = Vec::new();
+    let mut frames = Vec::new();
+    let mut legacy = Vec::new();
+
+    for run in 0..RUNS {
+        let probe = Probe::default();
+        let mut sp = spinner(&probe);
+        wait_for_run(run);
+
+        probe.watch("Still measuring...");
+        let start = Instant::now();
+        sp.set_message("Still measuring...");
+        message.push(probe.wait() - start);
+        sp.stop();
+
+        let probe = Probe::default();
+        let mut sp = spinner(&probe);
+        wait_for_run(run);
+
+        probe.watch("<>");
+        let start = Instant::now();
+        sp.set_spinner(CustomSpinner::new("probe", vec!["<>", "><"], 500));
+        frames.push(probe.wait() - start);
+        sp.stop();
+
+        let probe = Probe::default();
+        let sender = legacy::start(Spinners::Toggle.get_frames(), "Measuring...", probe.clone());
+        wait_for_run(run);
+
+        probe.watch("Still measuring...");
+        let start = Instant::now();
+        let _ = sender.send(legacy::Event::SetMessage("Still measuring...".to_string()));
+        legacy.push(probe.wait() - start);
+        let _ = sender.send(legacy::Event::Stop);
+    }
+
+    eprintln!();
+    report("set_message", message);
+    report("set_spinner", frames);
+    report("2.3.0", legacy);
+}
@@ -7,7 +7,7 @@ use strum::IntoEnumIterator;
 fn main() {
     let sps = Spinners::iter().collect::<Vec<Spinners>>();
     let len = sps.len();
-    let sp = sps.get(0).unwrap();
+    let sp = sps.first().unwrap();
     let mut spinner: Spinner = (*sp).into();
     spinner.start();
 
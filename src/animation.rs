@@ -0,0 +1,91 @@
+/// The order in which the frames of a spinner are shown
+///
+/// Set with [`Spinner::set_animation`](crate::Spinner::set_animation).
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::{Animation, Spinner, Spinners};
+/// use std::time::{Duration, Instant};
+///
+/// let mut sp: Spinner = Spinners::Arrow3.into();
+/// sp.set_interval(100);
+/// sp.set_animation(Animation::PingPong);
+///
+/// let now = Instant::now();
+/// let frames = (0..12)
+///     .map(|i| sp.current_frame(now + Duration::from_millis(100 * i)))
+///     .collect::<Vec<_>>();
+///
+/// // Bounces back from the last frame, without showing it twice
+/// assert_eq!(frames[4], frames[6]);
+/// assert_eq!(frames[0], frames[10]);
+/// assert_ne!(frames[5], frames[6]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum Animation {
+    /// From the first frame to the last, then starting over
+    #[default]
+    Forward,
+    /// From the last frame to the first, then starting over
+    Reverse,
+    /// From the first frame to the last and back again, without repeating the frames at either end
+    PingPong,
+    /// Every frame once per cycle, in a random order
+    Random,
+}
+
+/// A small, fast pseudo random number generator (SplitMix64), good enough for shuffling frames
+fn split_mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+impl Animation {
+    /// The amount of ticks before the animation repeats itself
+    pub(crate) fn period(self, len: usize) -> usize {
+        match self {
+            _ if len <= 1 => 1,
+            Animation::PingPong => 2 * len - 2,
+            Animation::Forward | Animation::Reverse | Animation::Random => len,
+        }
+    }
+
+    /// The index of the frame shown on the given tick
+    ///
+    /// This only depends on its arguments, so a random animation gives the same frame for the same tick and seed.
+    pub(crate) fn index(self, tick: u128, len: usize, seed: u64) -> usize {
+        if len <= 1 {
+            return 0;
+        }
+
+        let position = (tick % self.period(len) as u128) as usize;
+
+        match self {
+            Animation::Forward => position,
+            Animation::Reverse => len - 1 - position,
+            Animation::PingPong if position < len => position,
+            Animation::PingPong => self.period(len) - position,
+            Animation::Random => {
+                // Shuffle the frames differently every cycle, using the cycle number as part of the seed
+                let cycle = (tick / len as u128) as u64;
+                let mut state = split_mix(seed ^ split_mix(cycle));
+                let mut order = (0..len).collect::<Vec<_>>();
+
+                for i in (1..len).rev() {
+                    state = split_mix(state);
+                    order.swap(i, (state % (i as u64 + 1)) as usize);
+                }
+
+                order[position]
+            }
+        }
+    }
+}
@@ -0,0 +1,369 @@
+use std::sync::Arc;
+
+use crate::{
+    state::State, template::Template, Animation, AsciiFallback, Clock, Color, DropBehavior, Error,
+    Mode, Output, Placement, Spinner, SpinnerStyle, Spinners, FAILURE_SYMBOL, SUCCESS_SYMBOL,
+};
+
+/// A builder for configuring a [`Spinner`]
+///
+/// Created with [`Spinner::builder`]. Every option has a default, so only the ones that matter need to be set.
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::{DropBehavior, Output, Spinner, Spinners};
+///
+/// let mut sp = Spinner::builder()
+///     .spinner(Spinners::Arrow)
+///     .message("Doing some cool things...")
+///     .interval(80)
+///     .output(Output::Stderr)
+///     .hide_cursor(true)
+///     .drop_behavior(DropBehavior::FailOnPanic)
+///     .start()
+///     .unwrap();
+///
+/// sp.stop_with_success();
+/// ```
+///
+/// With the `serde` feature enabled, the builder can also be read from a config file. Spinners are named the same way they are displayed, and the options left out keep their defaults.
+/// Options and their values are both written in camelCase.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use spinners_rs::SpinnerBuilder;
+///
+/// let config = r#"
+///     spinner = "arrow3"
+///     message = "Doing some cool things..."
+///     interval = 80
+///     output = "stderr"
+///     animation = "pingPong"
+///     color = "cyan"
+///     hideCursor = true
+///     dropBehavior = "failOnPanic"
+///     successSymbol = "OK"
+///     failureSymbol = "ERR"
+///
+///     [placement]
+///     side = "right"
+///     brackets = ["[", "]"]
+/// "#;
+///
+/// let mut sp = toml::from_str::<SpinnerBuilder>(config).unwrap().start().unwrap();
+///
+/// sp.stop_with_success();
+///
+/// // Custom spinners are written out in full
+/// let config = r#"spinner = { name = "pulse", frames = ["·", "•", "●", "•"], interval = 150 }"#;
+///
+/// let sp = toml::from_str::<SpinnerBuilder>(config).unwrap().build().unwrap();
+/// assert_eq!(sp.get_name(), "Pulse");
+///
+/// // Misspelt names suggest the closest ones
+/// let e = toml::from_str::<SpinnerBuilder>(r#"spinner = "dotz""#).unwrap_err();
+/// assert!(e.to_string().contains("did you mean `dots`"));
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, rename_all = "camelCase")
+)]
+pub struct SpinnerBuilder {
+    spinner: SpinnerStyle,
+    message: String,
+    prefix: String,
+    suffix: String,
+    placement: Placement,
+    template: Option<String>,
+    color: Option<Color>,
+    animation: Animation,
+    interval: Option<u64>,
+    output: Output,
+    hide_cursor: bool,
+    drop_behavior: DropBehavior,
+    success_symbol: String,
+    failure_symbol: String,
+    fallback: AsciiFallback,
+    ascii_fallback: bool,
+    env_overrides: bool,
+    mode: Mode,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    clock: Option<Arc<dyn Clock>>,
+}
+
+impl Default for SpinnerBuilder {
+    fn default() -> Self {
+        Self {
+            spinner: Spinners::Dots.into(),
+            message: String::new(),
+            prefix: String::new(),
+            suffix: String::new(),
+            placement: Placement::default(),
+            template: None,
+            color: None,
+            animation: Animation::default(),
+            interval: None,
+            output: Output::default(),
+            hide_cursor: false,
+            drop_behavior: DropBehavior::default(),
+            success_symbol: SUCCESS_SYMBOL.to_string(),
+            failure_symbol: FAILURE_SYMBOL.to_string(),
+            fallback: AsciiFallback::default(),
+            ascii_fallback: true,
+            env_overrides: true,
+            mode: Mode::default(),
+            clock: None,
+        }
+    }
+}
+
+impl SpinnerBuilder {
+    /// Creates a builder with the default options, the same as [`Spinner::builder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the spinner to use, defaults to [`Spinners::Dots`]
+    ///
+    /// This can also be a [`CustomSpinner`](crate::CustomSpinner).
+    pub fn spinner(mut self, spinner: impl Into<SpinnerStyle>) -> Self {
+        self.spinner = spinner.into();
+        self
+    }
+
+    /// Sets the message to display next to the spinner, defaults to no message
+    pub fn message(mut self, message: impl std::fmt::Display) -> Self {
+        self.message = message.to_string();
+        self
+    }
+
+    /// Sets the text shown before the spinner frame, see [`Spinner::set_prefix`]
+    pub fn prefix(mut self, prefix: impl std::fmt::Display) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Sets the text shown after the spinner message, see [`Spinner::set_suffix`]
+    pub fn suffix(mut self, suffix: impl std::fmt::Display) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Sets where the frame is placed relative to the message, see [`Placement`]
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Lays out the spinner line with a template, instead of the prefix, placement and suffix
+    ///
+    /// `{prefix}`, `{frame}`, `{message}` and `{suffix}` are replaced with the parts of the line, and `{{` and `}}` stand for plain braces.
+    /// The final line shown once the spinner is stopped uses the same template, with the symbol in place of the frame and without the suffix.
+    /// Spaces left at either end of the line by empty parts are trimmed.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinner, Spinners, TestTerminal};
+    ///
+    /// let mut sp = Spinner::builder()
+    ///     .spinner(Spinners::Line)
+    ///     .message("Compiling")
+    ///     .prefix("2/3")
+    ///     .template("[{prefix}] {message}... {frame}")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut terminal = TestTerminal::new();
+    /// sp.render_to(&mut terminal).unwrap();
+    ///
+    /// assert_eq!(terminal.screen(), "[2/3] Compiling... -");
+    ///
+    /// // Mistakes are caught when building the spinner
+    /// assert!(Spinner::builder().template("{spinner} {message}").build().is_err());
+    /// ```
+    pub fn template(mut self, template: impl std::fmt::Display) -> Self {
+        self.template = Some(template.to_string());
+        self
+    }
+
+    /// Sets the color of the frame while the spinner is running, defaults to no color, see [`Color`]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the order the frames are shown in, defaults to [`Animation::Forward`]
+    pub fn animation(mut self, animation: Animation) -> Self {
+        self.animation = animation;
+        self
+    }
+
+    /// Sets the time between frames in milliseconds
+    ///
+    /// Defaults to the interval of the spinner style, as with [`Spinner::new`].
+    pub fn interval(mut self, interval: u64) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Sets where the spinner is drawn, defaults to [`Output::Stdout`]
+    pub fn output(mut self, output: Output) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// Sets whether the cursor is hidden while the spinner is running, defaults to `false`
+    ///
+    /// The cursor is shown again when the spinner is stopped or paused.
+    pub fn hide_cursor(mut self, hide_cursor: bool) -> Self {
+        self.hide_cursor = hide_cursor;
+        self
+    }
+
+    /// Sets what happens when the spinner is dropped while still running, defaults to [`DropBehavior::Persist`]
+    pub fn drop_behavior(mut self, drop_behavior: DropBehavior) -> Self {
+        self.drop_behavior = drop_behavior;
+        self
+    }
+
+    /// Sets the symbol shown when the spinner succeeds, defaults to [`SUCCESS_SYMBOL`], see [`Spinner::set_success_symbol`]
+    pub fn success_symbol(mut self, symbol: impl std::fmt::Display) -> Self {
+        self.success_symbol = symbol.to_string();
+        self
+    }
+
+    /// Sets the symbol shown when the spinner fails, defaults to [`FAILURE_SYMBOL`], see [`Spinner::set_failure_symbol`]
+    pub fn failure_symbol(mut self, symbol: impl std::fmt::Display) -> Self {
+        self.failure_symbol = symbol.to_string();
+        self
+    }
+
+    /// Sets which spinner is shown instead when the terminal can't show Unicode, see [`AsciiFallback`]
+    pub fn fallback(mut self, fallback: AsciiFallback) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Sets whether spinners that aren't ASCII are swapped out when the terminal can't show them, see [`Spinner::set_ascii_fallback`]
+    pub fn ascii_fallback(mut self, enabled: bool) -> Self {
+        self.ascii_fallback = enabled;
+        self
+    }
+
+    /// Sets whether the spinner can be changed through environment variables, see [`Spinner::set_env_overrides`]
+    pub fn env_overrides(mut self, enabled: bool) -> Self {
+        self.env_overrides = enabled;
+        self
+    }
+
+    /// Sets how the spinner is shown while running, see [`Spinner::set_mode`]
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets where the spinner gets the current time from, see [`Spinner::set_clock`]
+    pub fn clock(mut self, clock: impl Clock) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Creates the spinner, without starting it
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if the options don't make sense, such as an interval or heartbeat of zero, a spinner without frames, a template that can't be read,
+    /// or a message, prefix, suffix or symbol that spans several lines and so can't be redrawn in place.
+    pub fn build(self) -> Result<Spinner, Error> {
+        let interval = self.interval.unwrap_or_else(|| self.spinner.interval());
+
+        if interval == 0 {
+            return Err(Error::Config(
+                "the interval must be at least 1ms".to_string(),
+            ));
+        }
+
+        if self.mode == Mode::Heartbeat(0) {
+            return Err(Error::Config(
+                "the heartbeat must be at least 1ms".to_string(),
+            ));
+        }
+
+        if self.spinner.frames().is_empty() {
+            return Err(Error::Config(format!(
+                "the spinner `{}` has no frames",
+                self.spinner
+            )));
+        }
+
+        for (name, text) in [
+            ("message", &self.message),
+            ("prefix", &self.prefix),
+            ("suffix", &self.suffix),
+            ("success symbol", &self.success_symbol),
+            ("failure symbol", &self.failure_symbol),
+        ] {
+            if text.contains(['\n', '\r']) {
+                return Err(Error::Config(format!(
+                    "the {} can't span several lines",
+                    name
+                )));
+            }
+        }
+
+        let template = self
+            .template
+            .as_deref()
+            .map(Template::parse)
+            .transpose()
+            .map_err(Error::Config)?;
+
+        let mut state = State::new(self.spinner.frames(), interval, self.message);
+        state.prefix = self.prefix;
+        state.suffix = self.suffix;
+        state.placement = self.placement;
+        state.template = template;
+        state.animation = self.animation;
+        state.output = self.output;
+        state.hide_cursor = self.hide_cursor;
+        if let Some(clock) = self.clock {
+            // The state was made by the system clock, so the animation starts over by the one given
+            let now = clock.now();
+            state.clock = clock;
+            state.started = now;
+            state.reset(now);
+        }
+
+        let mut spinner = Spinner::from_state(self.spinner, state, self.fallback);
+        if let Some(interval) = self.interval {
+            spinner.set_interval(interval);
+        }
+        spinner.set_drop_behavior(self.drop_behavior);
+        spinner.set_success_symbol(self.success_symbol);
+        spinner.set_failure_symbol(self.failure_symbol);
+        spinner.set_ascii_fallback(self.ascii_fallback);
+        spinner.set_env_overrides(self.env_overrides);
+        spinner.set_mode(self.mode);
+        spinner.set_color(self.color);
+
+        Ok(spinner)
+    }
+
+    /// Creates the spinner and starts it straight away
+    ///
+    /// # Errors
+    ///
+    /// See [`SpinnerBuilder::build`] and [`Spinner::try_start`].
+    pub fn start(self) -> Result<Spinner, Error> {
+        let mut spinner = self.build()?;
+        spinner.try_start()?;
+
+        Ok(spinner)
+    }
+}
@@ -0,0 +1,157 @@
+use strum::{Display, EnumIter, EnumString, IntoEnumIterator};
+
+use crate::Spinners;
+
+/// The kinds of spinners there are, for narrowing down which one to use
+///
+/// A spinner can be in more than one category, see [`Spinners::categories`].
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::{Category, Spinners};
+///
+/// let toggles = Spinners::in_category(Category::Toggles).count();
+/// assert_eq!(toggles, 13);
+///
+/// assert!(Spinners::Christmas.categories().contains(&Category::Seasonal));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, Display, EnumString)]
+#[strum(serialize_all = "camelCase")]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum Category {
+    /// Made out of Braille patterns, such as [`Spinners::Dots`]
+    Braille,
+    /// Arrows pointing around, such as [`Spinners::Arrow`]
+    Arrows,
+    /// Made out of emoji, such as [`Spinners::Earth`]
+    Emoji,
+    /// Bars that fill up or bounce, such as [`Spinners::BouncingBar`]
+    Bars,
+    /// Switching between two or three symbols, such as [`Spinners::Toggle`]
+    Toggles,
+    /// Tied to a time of the year, such as [`Spinners::Christmas`]
+    Seasonal,
+    /// Lines turning or stretching, such as [`Spinners::Line`]
+    Lines,
+    /// Geometric shapes turning or filling up, such as [`Spinners::Arc`]
+    Shapes,
+    /// Dots trailing off like text, such as [`Spinners::SimpleDots`]
+    Ellipsis,
+    /// Letters changing shape, such as [`Spinners::Dqpb`]
+    Letters,
+    /// Little scenes of something moving across, such as [`Spinners::Pong`]
+    Scenes,
+}
+
+impl Spinners {
+    /// Gets the categories the spinner is in
+    pub const fn categories(&self) -> &'static [Category] {
+        use Category::*;
+
+        match *self {
+            Spinners::Dots
+            | Spinners::Dots2
+            | Spinners::Dots3
+            | Spinners::Dots4
+            | Spinners::Dots5
+            | Spinners::Dots6
+            | Spinners::Dots7
+            | Spinners::Dots8
+            | Spinners::Dots9
+            | Spinners::Dots10
+            | Spinners::Dots11
+            | Spinners::Dots8Bit
+            | Spinners::Bounce => &[Braille],
+            Spinners::Line2 => &[Lines, Braille],
+            Spinners::Pong => &[Scenes, Braille],
+            Spinners::Arrow | Spinners::Arrow3 => &[Arrows],
+            Spinners::Arrow2 => &[Arrows, Emoji],
+            Spinners::Smiley
+            | Spinners::Monkey
+            | Spinners::Hearts
+            | Spinners::Clock
+            | Spinners::Earth
+            | Spinners::Moon
+            | Spinners::Runner
+            | Spinners::FingerDance
+            | Spinners::Mindblown
+            | Spinners::Speaker
+            | Spinners::OrangePulse
+            | Spinners::BluePulse
+            | Spinners::OrangeBluePulse
+            | Spinners::TimeTravel => &[Emoji],
+            Spinners::Weather | Spinners::Christmas => &[Emoji, Seasonal],
+            Spinners::FistBump | Spinners::SoccerHeader => &[Emoji, Scenes],
+            Spinners::BouncingBar
+            | Spinners::Material
+            | Spinners::Aesthetic
+            | Spinners::GrowVertical
+            | Spinners::GrowHorizontal => &[Bars],
+            Spinners::Toggle
+            | Spinners::Toggle2
+            | Spinners::Toggle3
+            | Spinners::Toggle4
+            | Spinners::Toggle5
+            | Spinners::Toggle6
+            | Spinners::Toggle7
+            | Spinners::Toggle8
+            | Spinners::Toggle9
+            | Spinners::Toggle10
+            | Spinners::Toggle11
+            | Spinners::Toggle12
+            | Spinners::Toggle13 => &[Toggles],
+            Spinners::Line | Spinners::Pipe | Spinners::Flip | Spinners::Layer => &[Lines],
+            Spinners::Star
+            | Spinners::Star2
+            | Spinners::Hamburger
+            | Spinners::Balloon2
+            | Spinners::Noise
+            | Spinners::BoxBounce
+            | Spinners::BoxBounce2
+            | Spinners::Triangle
+            | Spinners::Arc
+            | Spinners::Circle
+            | Spinners::SquareCorners
+            | Spinners::CircleQuaters
+            | Spinners::CircleHalves
+            | Spinners::Squish => &[Shapes],
+            Spinners::SimpleDots | Spinners::SimpleDotsScrolling | Spinners::Point => &[Ellipsis],
+            Spinners::Dqpb | Spinners::Balloon | Spinners::BetaWave => &[Letters],
+            Spinners::BouncingBall | Spinners::Shark | Spinners::Grenade => &[Scenes],
+        }
+    }
+
+    /// Whether the spinner is in the given category
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Category, Spinners};
+    ///
+    /// assert!(Spinners::Dots.is_in(Category::Braille));
+    /// assert!(!Spinners::Dots.is_in(Category::Emoji));
+    /// ```
+    pub fn is_in(&self, category: Category) -> bool {
+        self.categories().contains(&category)
+    }
+
+    /// Iterates over every spinner in the given category, in the same order as [`Spinners::iter`](strum::IntoEnumIterator::iter)
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Category, Spinners};
+    ///
+    /// let arrows = Spinners::in_category(Category::Arrows).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(arrows, [Spinners::Arrow, Spinners::Arrow2, Spinners::Arrow3]);
+    /// ```
+    pub fn in_category(category: Category) -> impl Iterator<Item = Spinners> {
+        Spinners::iter().filter(move |spinner| spinner.is_in(category))
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use crate::scheduler::{Message, Scheduler};
+
+/// Where a spinner gets the current time from
+///
+/// Frames are worked out from the time, so swapping the clock changes what is drawn and when.
+/// Spinners use the [`SystemClock`] unless given another one with [`Spinner::set_clock`](crate::Spinner::set_clock).
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::{Clock, Spinner, Spinners};
+/// use std::time::{Duration, Instant};
+///
+/// /// A clock that is always an hour ahead
+/// #[derive(Debug)]
+/// struct Ahead;
+///
+/// impl Clock for Ahead {
+///     fn now(&self) -> Instant {
+///         Instant::now() + Duration::from_secs(3600)
+///     }
+/// }
+///
+/// let mut sp = Spinner::new(Spinners::Dots, "Doing some cool things...");
+/// sp.set_clock(Ahead);
+/// ```
+pub trait Clock: fmt::Debug + Send + Sync + 'static {
+    /// The current time
+    fn now(&self) -> Instant;
+
+    /// The manual clock that draws the spinners using this clock, instead of the render thread
+    ///
+    /// Only [`ManualClock`] returns one. Spinners using any other clock are drawn by the render thread, which reads their clock every time it draws them.
+    /// It waits for the next frame in real time, so clocks that run faster or slower than real time skip or repeat frames.
+    fn as_manual(&self) -> Option<&ManualClock> {
+        None
+    }
+}
+
+/// The clock of the operating system, used by default
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock that only moves when it is told to, for testing what spinners draw
+///
+/// Running spinners using this clock aren't drawn by the render thread. Instead, every frame that comes due is drawn while the clock is advanced, and events such as [`Spinner::set_message`](crate::Spinner::set_message) are drawn straight away.
+/// Once a call returns, everything it caused has been written, so tests don't need to sleep or retry.
+///
+/// Clones share the same time, so one can be given to the spinner and another kept to move it along.
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::{Clock, ManualClock, Mode, Spinner, Spinners};
+/// use std::time::Duration;
+///
+/// let clock = ManualClock::new();
+///
+/// let mut sp = Spinner::builder()
+///     .spinner(Spinners::Line)
+///     .message("Waiting...")
+///     .interval(100)
+///     .mode(Mode::Animated)
+///     .clock(clock.clone())
+///     .start()
+///     .unwrap();
+///
+/// assert_eq!(sp.current_frame(clock.now()), "-");
+///
+/// clock.advance(Duration::from_millis(250));
+/// assert_eq!(sp.current_frame(clock.now()), "|");
+///
+/// sp.stop();
+/// ```
+#[derive(Clone)]
+pub struct ManualClock {
+    inner: Arc<Mutex<Manual>>,
+}
+
+/// The time of a [`ManualClock`], along with the spinners it draws
+struct Manual {
+    now: Instant,
+    scheduler: Scheduler,
+}
+
+impl ManualClock {
+    /// Creates a clock stopped at the current time
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Manual {
+                now: Instant::now(),
+                scheduler: Scheduler::new(),
+            })),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Manual> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Moves the clock forward, drawing every frame that comes due on the way
+    pub fn advance(&self, by: Duration) {
+        let mut manual = self.lock();
+        let until = manual.now + by;
+
+        // Stop at every deadline, so each spinner is drawn just as often as the render thread would draw it
+        while let Some(due) = manual.scheduler.next_deadline().filter(|due| *due <= until) {
+            manual.now = manual.now.max(due);
+
+            let now = manual.now;
+            manual.scheduler.draw(now);
+        }
+
+        manual.now = until;
+    }
+
+    /// Handles a message for one of the spinners using this clock, drawing it if it is due
+    pub(crate) fn handle(&self, message: Message) {
+        let mut manual = self.lock();
+        let now = manual.now;
+
+        manual.scheduler.handle(message, now);
+        manual.scheduler.draw(now);
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.lock().now
+    }
+
+    fn as_manual(&self) -> Option<&ManualClock> {
+        Some(self)
+    }
+}
+
+impl fmt::Debug for ManualClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The time is left out, as reading it means waiting for the clock to finish drawing
+        f.debug_struct("ManualClock").finish_non_exhaustive()
+    }
+}
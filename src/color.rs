@@ -0,0 +1,64 @@
+/// The color the spinner frame is drawn in
+///
+/// Set with [`SpinnerBuilder::color`](crate::SpinnerBuilder::color). Only the frame of a running, animated spinner is colored, the message and the final line are left as they are.
+/// Colors are left out when the `NO_COLOR` environment variable is set, unless [`Spinner::set_env_overrides`](crate::Spinner::set_env_overrides) is turned off.
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::{Color, Spinner, Spinners};
+///
+/// let mut sp = Spinner::builder()
+///     .spinner(Spinners::Dots)
+///     .message("Doing some cool things...")
+///     .color(Color::Cyan)
+///     .start()
+///     .unwrap();
+///
+/// sp.stop_with_success();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum Color {
+    /// Black
+    Black,
+    /// Red
+    Red,
+    /// Green
+    Green,
+    /// Yellow
+    Yellow,
+    /// Blue
+    Blue,
+    /// Magenta
+    Magenta,
+    /// Cyan
+    Cyan,
+    /// White
+    White,
+}
+
+impl Color {
+    /// The ANSI foreground color code
+    fn code(self) -> u8 {
+        match self {
+            Color::Black => 30,
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+        }
+    }
+
+    /// Wraps the text in the escape sequences that color it, going back to the default color after
+    pub(crate) fn paint(self, text: &str) -> String {
+        format!("\x1b[{}m{}\x1b[39m", self.code(), text)
+    }
+}
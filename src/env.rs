@@ -0,0 +1,130 @@
+use std::env;
+
+use crate::Spinners;
+
+/// Turns off the animation when set to `off`, `0` or `false`, leaving only the final line written when a spinner is stopped
+pub const ENV_ENABLED: &str = "SPINNERS_RS";
+
+/// Shows the given spinner instead of the one chosen by the program, by any name [`Spinners`] can be parsed from
+pub const ENV_STYLE: &str = "SPINNERS_RS_STYLE";
+
+/// Shows spinners in [`Mode::Accessible`](crate::Mode::Accessible) when set to `1`, `true` or `on`, whatever mode the program asked for
+pub const ENV_ACCESSIBLE: &str = "SPINNERS_RS_ACCESSIBLE";
+
+/// Multiplies the time between frames, such as `2` to halve the amount of redraws over a slow SSH connection
+pub const ENV_INTERVAL_MULTIPLIER: &str = "SPINNERS_RS_INTERVAL_MULTIPLIER";
+
+/// Leaves out the colors of every spinner when set to anything, following <https://no-color.org>
+const ENV_NO_COLOR: &str = "NO_COLOR";
+
+/// The changes asked for through the environment, read when a spinner is started
+///
+/// Values that can't be understood are ignored, as there is no good way to report them.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct Overrides {
+    pub disabled: bool,
+    pub accessible: bool,
+    pub no_color: bool,
+    pub style: Option<Spinners>,
+    pub interval_multiplier: Option<f64>,
+}
+
+impl Overrides {
+    pub fn from_env() -> Self {
+        Self::from_lookup(|name| env::var(name).ok())
+    }
+
+    /// Reads the overrides from the given environment variables
+    fn from_lookup(var: impl Fn(&str) -> Option<String>) -> Self {
+        let disabled = var(ENV_ENABLED).is_some_and(|value| {
+            matches!(value.trim().to_lowercase().as_str(), "off" | "0" | "false")
+        });
+
+        let accessible = var(ENV_ACCESSIBLE).is_some_and(|value| {
+            matches!(value.trim().to_lowercase().as_str(), "1" | "true" | "on")
+        });
+
+        let no_color = var(ENV_NO_COLOR).is_some_and(|value| !value.is_empty());
+
+        let style = var(ENV_STYLE).and_then(|name| name.trim().parse().ok());
+
+        let interval_multiplier = var(ENV_INTERVAL_MULTIPLIER)
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .filter(|multiplier| multiplier.is_finite() && *multiplier > 0.0);
+
+        Self {
+            disabled,
+            accessible,
+            no_color,
+            style,
+            interval_multiplier,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_env::lookup;
+
+    /// Reads the overrides from an environment made of the given variables
+    fn overrides(vars: &[(&str, &str)]) -> Overrides {
+        Overrides::from_lookup(lookup(vars))
+    }
+
+    #[test]
+    fn nothing_is_overridden_by_default() {
+        assert_eq!(overrides(&[]), Overrides::default());
+    }
+
+    #[test]
+    fn reads_every_override() {
+        let read = overrides(&[
+            (ENV_ENABLED, "off"),
+            (ENV_ACCESSIBLE, "1"),
+            (ENV_STYLE, " line "),
+            (ENV_INTERVAL_MULTIPLIER, "2.5"),
+            (ENV_NO_COLOR, "1"),
+        ]);
+
+        assert_eq!(
+            read,
+            Overrides {
+                disabled: true,
+                accessible: true,
+                no_color: true,
+                style: Some(Spinners::Line),
+                interval_multiplier: Some(2.5),
+            }
+        );
+    }
+
+    #[test]
+    fn switches_accept_a_few_spellings() {
+        for value in ["off", "OFF", "0", "false"] {
+            assert!(overrides(&[(ENV_ENABLED, value)]).disabled);
+        }
+
+        for value in ["on", "1", "True"] {
+            assert!(overrides(&[(ENV_ACCESSIBLE, value)]).accessible);
+        }
+
+        assert!(!overrides(&[(ENV_ENABLED, "on")]).disabled);
+        assert!(!overrides(&[(ENV_NO_COLOR, "")]).no_color);
+    }
+
+    #[test]
+    fn values_that_cant_be_understood_are_ignored() {
+        let read = overrides(&[
+            (ENV_STYLE, "dotz"),
+            (ENV_INTERVAL_MULTIPLIER, "-1"),
+            (ENV_ACCESSIBLE, "yes please"),
+        ]);
+
+        assert_eq!(read, Overrides::default());
+        assert_eq!(
+            overrides(&[(ENV_INTERVAL_MULTIPLIER, "NaN")]).interval_multiplier,
+            None
+        );
+    }
+}
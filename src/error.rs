@@ -0,0 +1,129 @@
+use std::{fmt, io};
+
+use crate::Spinners;
+
+/// The errors that can occur while running a spinner
+///
+/// Returned by the `try_*` methods on [`Spinner`], such as [`Spinner::try_set_message`], and by [`SpinnerBuilder::build`].
+///
+/// [`Spinner`]: crate::Spinner
+/// [`Spinner::try_set_message`]: crate::Spinner::try_set_message
+/// [`SpinnerBuilder::build`]: crate::SpinnerBuilder::build
+#[derive(Debug)]
+pub enum Error {
+    /// The spinner is no longer connected to the render thread, so it can't be updated
+    ///
+    /// This is returned once the render thread has gone away, and by every call after the spinner stopped because of an error, until it is started again.
+    Disconnected,
+    /// Writing the spinner to the terminal failed
+    ///
+    /// The spinner is stopped once this has been reported.
+    Io(io::Error),
+    /// The spinner was configured with options that don't make sense
+    Config(String),
+}
+
+impl Error {
+    /// Whether the spinner couldn't be written because the reader went away
+    ///
+    /// This happens when the output is piped into another program that exits early, such as `head`.
+    /// Every running spinner is stopped when this happens, and it is usually best to exit quietly.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinners, Spinner};
+    ///
+    /// let mut sp: Spinner = Spinners::Dots.into();
+    /// sp.start();
+    ///
+    /// if let Err(e) = sp.try_stop_with_success() {
+    ///     if !e.is_broken_pipe() {
+    ///         eprintln!("{}", e);
+    ///     }
+    /// }
+    /// ```
+    pub fn is_broken_pipe(&self) -> bool {
+        matches!(self, Error::Io(e) if e.kind() == io::ErrorKind::BrokenPipe)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Disconnected => write!(f, "the spinner render thread is no longer running"),
+            Error::Io(e) => write!(f, "failed to write the spinner: {}", e),
+            Error::Config(reason) => write!(f, "invalid spinner configuration: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Disconnected | Error::Config(_) => None,
+            Error::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// The error returned when parsing a spinner name that doesn't exist
+///
+/// Suggests the spinners with the closest names, which makes for a helpful message when the name comes from a config file or command line flag.
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::Spinners;
+///
+/// let e = "dotz".parse::<Spinners>().unwrap_err();
+///
+/// assert_eq!(e.suggestions()[0], Spinners::Dots);
+/// assert_eq!(
+///     e.to_string(),
+///     "unknown spinner `dotz`, did you mean `dots`, `dots2` or `dots3`?"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSpinnerError {
+    pub(crate) name: String,
+    pub(crate) suggestions: Vec<Spinners>,
+}
+
+impl ParseSpinnerError {
+    /// The name that couldn't be parsed
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The spinners with the closest names, the closest first
+    pub fn suggestions(&self) -> &[Spinners] {
+        &self.suggestions
+    }
+}
+
+impl fmt::Display for ParseSpinnerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown spinner `{}`", self.name)?;
+
+        let names = self
+            .suggestions
+            .iter()
+            .map(|spinner| format!("`{}`", spinner))
+            .collect::<Vec<_>>();
+
+        match names.split_last() {
+            None => Ok(()),
+            Some((last, [])) => write!(f, ", did you mean {}?", last),
+            Some((last, rest)) => write!(f, ", did you mean {} or {}?", rest.join(", "), last),
+        }
+    }
+}
+
+impl std::error::Error for ParseSpinnerError {}
@@ -0,0 +1,213 @@
+use std::{collections::HashMap, env};
+
+use crate::{SpinnerStyle, Spinners};
+
+/// Whether the terminal is likely to be able to show spinners that aren't plain ASCII
+///
+/// This looks at the environment the program was started in:
+///
+/// - `TERM=linux` (the Linux console), `TERM=dumb` and serial terminals such as `vt100` can't show Braille or emoji.
+/// - Otherwise the locale decides, from the first of `LC_ALL`, `LC_CTYPE` and `LANG` that is set. It needs to be a UTF-8 one.
+/// - On Windows the legacy console can't show them, while Windows Terminal and the VS Code terminal can.
+///
+/// When none of these are set, Unicode is assumed to work.
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::{supports_unicode, Spinner, Spinners};
+///
+/// let spinner = if supports_unicode() {
+///     Spinners::Dots
+/// } else {
+///     Spinners::Line
+/// };
+///
+/// let mut sp = Spinner::new(spinner, "Doing some cool things...");
+/// sp.start();
+/// sp.stop();
+/// ```
+pub fn supports_unicode() -> bool {
+    detect(|name| env::var(name).ok(), cfg!(windows))
+}
+
+/// The detection behind [`supports_unicode`], with the environment passed in
+fn detect(var: impl Fn(&str) -> Option<String>, windows: bool) -> bool {
+    let term = var("TERM").unwrap_or_default();
+
+    if matches!(term.as_str(), "linux" | "dumb") || term.starts_with("vt") {
+        return false;
+    }
+
+    if windows {
+        return var("WT_SESSION").is_some()
+            || var("TERM_PROGRAM").as_deref() == Some("vscode")
+            || !term.is_empty();
+    }
+
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|name| var(name))
+        .find(|value| !value.is_empty());
+
+    match locale {
+        Some(locale) => {
+            let locale = locale.to_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        }
+        None => true,
+    }
+}
+
+/// Which spinner to show instead, when the terminal can't show the one asked for
+///
+/// Only the built in [`Spinners`] whose frames aren't plain ASCII are swapped out, see [`Spinners::is_ascii`].
+/// By default they are all swapped for [`Spinners::Line`], but a different spinner can be picked for each of them.
+/// Whether the terminal can show them is detected with [`supports_unicode`], unless set otherwise.
+///
+/// Set with [`SpinnerBuilder::fallback`](crate::SpinnerBuilder::fallback). [`Spinner::new`](crate::Spinner::new) uses the default.
+/// The spinner is swapped out when it is started, and can be left alone with [`Spinner::set_ascii_fallback`](crate::Spinner::set_ascii_fallback).
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::{AsciiFallback, Spinner, Spinners};
+///
+/// let fallback = AsciiFallback::new()
+///     .unicode(false)
+///     .map(Spinners::Arrow3, Spinners::BouncingBar)
+///     .default_spinner(Spinners::SimpleDots);
+///
+/// assert_eq!(fallback.resolve(Spinners::Arrow3), Spinners::BouncingBar);
+/// assert_eq!(fallback.resolve(Spinners::Dots), Spinners::SimpleDots);
+/// assert_eq!(fallback.resolve(Spinners::Toggle13), Spinners::Toggle13);
+///
+/// let mut sp = Spinner::builder()
+///     .spinner(Spinners::Dots)
+///     .fallback(fallback)
+///     .start()
+///     .unwrap();
+///
+/// sp.stop();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, rename_all = "camelCase")
+)]
+pub struct AsciiFallback {
+    unicode: Option<bool>,
+    default: SpinnerStyle,
+    mapping: HashMap<Spinners, SpinnerStyle>,
+}
+
+impl Default for AsciiFallback {
+    fn default() -> Self {
+        Self {
+            unicode: None,
+            default: Spinners::Line.into(),
+            mapping: HashMap::new(),
+        }
+    }
+}
+
+impl AsciiFallback {
+    /// Swaps every spinner that isn't ASCII for [`Spinners::Line`], if the terminal doesn't seem to support Unicode
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Never swaps out any spinners
+    pub fn never() -> Self {
+        Self::new().unicode(true)
+    }
+
+    /// Sets whether the terminal supports Unicode, instead of detecting it
+    pub fn unicode(mut self, supported: bool) -> Self {
+        self.unicode = Some(supported);
+        self
+    }
+
+    /// Sets the spinner shown in place of the ones without a spinner of their own, defaults to [`Spinners::Line`]
+    pub fn default_spinner(mut self, spinner: impl Into<SpinnerStyle>) -> Self {
+        self.default = spinner.into();
+        self
+    }
+
+    /// Sets the spinner shown in place of `from`
+    pub fn map(mut self, from: Spinners, to: impl Into<SpinnerStyle>) -> Self {
+        self.mapping.insert(from, to.into());
+        self
+    }
+
+    /// Whether the terminal supports Unicode, as set or detected
+    pub fn is_unicode_supported(&self) -> bool {
+        self.unicode.unwrap_or_else(supports_unicode)
+    }
+
+    /// The spinner to show in place of the given one
+    pub fn resolve(&self, spinner: impl Into<SpinnerStyle>) -> SpinnerStyle {
+        match spinner.into() {
+            SpinnerStyle::Builtin(spinner)
+                if !spinner.is_ascii() && !self.is_unicode_supported() =>
+            {
+                self.mapping
+                    .get(&spinner)
+                    .cloned()
+                    .unwrap_or_else(|| self.default.clone())
+            }
+            style => style,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::detect;
+    use crate::test_env::lookup;
+
+    /// Detects Unicode support in an environment made of the given variables
+    fn detect_in(vars: &[(&str, &str)], windows: bool) -> bool {
+        detect(lookup(vars), windows)
+    }
+
+    #[test]
+    fn terminals_without_unicode() {
+        assert!(!detect_in(
+            &[("TERM", "linux"), ("LANG", "en_US.UTF-8")],
+            false
+        ));
+        assert!(!detect_in(&[("TERM", "dumb")], false));
+        assert!(!detect_in(&[("TERM", "vt100")], true));
+    }
+
+    #[test]
+    fn the_first_locale_set_decides() {
+        assert!(detect_in(&[("LANG", "en_US.UTF-8")], false));
+        assert!(detect_in(
+            &[("LC_CTYPE", "de_DE.utf8"), ("LANG", "C")],
+            false
+        ));
+        assert!(!detect_in(
+            &[("LC_ALL", "C"), ("LANG", "en_US.UTF-8")],
+            false
+        ));
+        assert!(detect_in(&[("LC_ALL", ""), ("LANG", "en_US.UTF-8")], false));
+        assert!(!detect_in(&[("LANG", "POSIX")], false));
+    }
+
+    #[test]
+    fn unicode_is_assumed_without_a_locale() {
+        assert!(detect_in(&[], false));
+        assert!(detect_in(&[("TERM", "xterm-256color")], false));
+    }
+
+    #[test]
+    fn only_modern_windows_terminals_support_unicode() {
+        assert!(!detect_in(&[], true));
+        assert!(detect_in(&[("WT_SESSION", "1")], true));
+        assert!(detect_in(&[("TERM_PROGRAM", "vscode")], true));
+        assert!(detect_in(&[("TERM", "xterm")], true));
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::{
+    spinner::{lock, Shared},
+    Error,
+};
+
+/// A handle for updating a [`Spinner`] from other threads
+///
+/// Created with [`Spinner::handle`]. Handles are cheap to clone, can be sent between threads, and don't affect the lifetime of the spinner:
+/// dropping a handle does nothing, while dropping the [`Spinner`] stops it for every handle.
+/// Updates made through a handle after the spinner has stopped are kept, but not drawn.
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::{Spinners, Spinner, SpinnerHandle};
+/// use std::thread;
+///
+/// fn assert_thread_safe<T: Send + Sync + Clone>() {}
+/// assert_thread_safe::<SpinnerHandle>();
+///
+/// let mut sp = Spinner::new(Spinners::Dots, "Downloading...");
+/// sp.start();
+///
+/// let workers = (0..4)
+///     .map(|i| {
+///         let handle = sp.handle();
+///         thread::spawn(move || handle.set_message(format!("Downloading part {}...", i)))
+///     })
+///     .collect::<Vec<_>>();
+///
+/// for worker in workers {
+///     worker.join().unwrap();
+/// }
+///
+/// sp.stop_with_success();
+/// ```
+///
+/// [`Spinner`]: crate::Spinner
+/// [`Spinner::handle`]: crate::Spinner::handle
+#[derive(Debug, Clone)]
+pub struct SpinnerHandle {
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl SpinnerHandle {
+    pub(crate) fn new(shared: Arc<Mutex<Shared>>) -> Self {
+        Self { shared }
+    }
+
+    fn shared(&self) -> MutexGuard<'_, Shared> {
+        lock(&self.shared)
+    }
+
+    /// Sets the message to display
+    ///
+    /// See [`Spinner::set_message`](crate::Spinner::set_message).
+    pub fn set_message<S: std::fmt::Display>(&self, message: S) {
+        let _ = self.try_set_message(message);
+    }
+
+    /// Sets the message to display, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// See [`Spinner::try_set_message`](crate::Spinner::try_set_message).
+    pub fn try_set_message<S: std::fmt::Display>(&self, message: S) -> Result<(), Error> {
+        self.shared().set_message(message.to_string())
+    }
+
+    /// Sets the text shown before the spinner frame
+    ///
+    /// See [`Spinner::set_prefix`](crate::Spinner::set_prefix).
+    pub fn set_prefix<S: std::fmt::Display>(&self, prefix: S) {
+        let _ = self.try_set_prefix(prefix);
+    }
+
+    /// Sets the text shown before the spinner frame, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// See [`Spinner::try_set_prefix`](crate::Spinner::try_set_prefix).
+    pub fn try_set_prefix<S: std::fmt::Display>(&self, prefix: S) -> Result<(), Error> {
+        self.shared().set_prefix(prefix.to_string())
+    }
+
+    /// Sets the text shown after the spinner message
+    ///
+    /// See [`Spinner::set_suffix`](crate::Spinner::set_suffix).
+    pub fn set_suffix<S: std::fmt::Display>(&self, suffix: S) {
+        let _ = self.try_set_suffix(suffix);
+    }
+
+    /// Sets the text shown after the spinner message, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// See [`Spinner::try_set_suffix`](crate::Spinner::try_set_suffix).
+    pub fn try_set_suffix<S: std::fmt::Display>(&self, suffix: S) -> Result<(), Error> {
+        self.shared().set_suffix(suffix.to_string())
+    }
+
+    /// Updates the frame interval
+    ///
+    /// See [`Spinner::set_interval`](crate::Spinner::set_interval).
+    pub fn set_interval(&self, interval: u64) {
+        let _ = self.try_set_interval(interval);
+    }
+
+    /// Updates the frame interval, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// See [`Spinner::try_set_interval`](crate::Spinner::try_set_interval).
+    pub fn try_set_interval(&self, interval: u64) -> Result<(), Error> {
+        self.shared().set_interval(interval)
+    }
+
+    /// Gets the message currently being displayed
+    pub fn get_message(&self) -> String {
+        self.shared().message()
+    }
+
+    /// Whether the spinner has been started and not yet stopped
+    pub fn is_running(&self) -> bool {
+        self.shared().is_running()
+    }
+
+    /// Whether the spinner is currently paused
+    pub fn is_paused(&self) -> bool {
+        self.shared().is_paused()
+    }
+}
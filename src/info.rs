@@ -0,0 +1,230 @@
+use crate::Spinners;
+
+/// What the frames of a spinner are made of, for picking one that the terminal can show
+///
+/// Returned by [`Spinners::info`]. Everything is worked out from the frames at compile time, so it can be used in constants.
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::{SpinnerInfo, Spinners};
+///
+/// const DOTS: SpinnerInfo = Spinners::Dots.info();
+///
+/// assert_eq!(DOTS.width, 1);
+/// assert!(DOTS.braille && DOTS.uniform_width);
+/// assert!(!DOTS.ascii && !DOTS.emoji);
+///
+/// assert!(Spinners::Line.info().ascii);
+/// assert!(Spinners::Earth.info().emoji);
+/// assert!(!Spinners::Grenade.info().uniform_width);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpinnerInfo {
+    /// The width of the widest frame, in terminal columns
+    pub width: usize,
+    /// Whether every frame takes up the same amount of columns
+    pub uniform_width: bool,
+    /// Whether every frame is plain ASCII
+    pub ascii: bool,
+    /// Whether any frame contains emoji
+    pub emoji: bool,
+    /// Whether any frame contains Braille patterns
+    pub braille: bool,
+}
+
+/// Decodes the character starting at byte `i`, along with its length in bytes
+const fn decode(bytes: &[u8], i: usize) -> (u32, usize) {
+    let first = bytes[i] as u32;
+
+    if first < 0x80 {
+        (first, 1)
+    } else if first < 0xE0 {
+        (((first & 0x1F) << 6) | (bytes[i + 1] as u32 & 0x3F), 2)
+    } else if first < 0xF0 {
+        let c = ((first & 0x0F) << 12)
+            | ((bytes[i + 1] as u32 & 0x3F) << 6)
+            | (bytes[i + 2] as u32 & 0x3F);
+        (c, 3)
+    } else {
+        let c = ((first & 0x07) << 18)
+            | ((bytes[i + 1] as u32 & 0x3F) << 12)
+            | ((bytes[i + 2] as u32 & 0x3F) << 6)
+            | (bytes[i + 3] as u32 & 0x3F);
+        (c, 4)
+    }
+}
+
+/// The variation selector that asks for a character to be drawn as emoji
+const EMOJI_PRESENTATION: u32 = 0xFE0F;
+
+/// Characters that take up no columns of their own
+const fn is_zero_width(c: u32) -> bool {
+    matches!(c, 0x0300..=0x036F | 0x200B..=0x200D | 0xFE00..=0xFE0F)
+}
+
+/// Emoji, including the symbols that terminals draw as emoji without being asked to
+const fn is_emoji(c: u32) -> bool {
+    matches!(
+        c,
+        0x231A..=0x231B
+            | 0x23E9..=0x23EC
+            | 0x23F0
+            | 0x23F3
+            | 0x25FD..=0x25FE
+            | 0x2614..=0x2615
+            | 0x2648..=0x2653
+            | 0x267F
+            | 0x2693
+            | 0x26A1
+            | 0x26AA..=0x26AB
+            | 0x26BD..=0x26BE
+            | 0x26C4..=0x26C5
+            | 0x26CE
+            | 0x26D4
+            | 0x26EA
+            | 0x26F2..=0x26F3
+            | 0x26F5
+            | 0x26FA
+            | 0x26FD
+            | 0x2705
+            | 0x270A..=0x270B
+            | 0x2728
+            | 0x274C
+            | 0x274E
+            | 0x2753..=0x2755
+            | 0x2757
+            | 0x2795..=0x2797
+            | 0x27B0
+            | 0x27BF
+            | 0x2B1B..=0x2B1C
+            | 0x2B50
+            | 0x2B55
+            | 0x1F000..=0x1FAFF
+    )
+}
+
+/// East Asian wide characters, which take up two columns like emoji do
+const fn is_wide(c: u32) -> bool {
+    is_emoji(c)
+        || matches!(
+            c,
+            0x1100..=0x115F
+                | 0x2E80..=0x303E
+                | 0x3041..=0x33FF
+                | 0x3400..=0x4DBF
+                | 0x4E00..=0x9FFF
+                | 0xA000..=0xA4CF
+                | 0xAC00..=0xD7A3
+                | 0xF900..=0xFAFF
+                | 0xFE30..=0xFE4F
+                | 0xFF00..=0xFF60
+                | 0xFFE0..=0xFFE6
+                | 0x20000..=0x3FFFD
+        )
+}
+
+/// The amount of terminal columns a frame takes up
+///
+/// This follows what most terminals do: emoji and East Asian wide characters take up two columns, combining marks and variation selectors none.
+/// A character followed by the emoji variation selector is drawn as emoji, so it takes up two columns as well.
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::frame_width;
+///
+/// assert_eq!(frame_width("[=  ]"), 5);
+/// assert_eq!(frame_width("⠋"), 1);
+/// assert_eq!(frame_width("🌍"), 2);
+/// assert_eq!(frame_width("⬆️"), 2);
+/// ```
+pub const fn frame_width(frame: &str) -> usize {
+    let bytes = frame.as_bytes();
+    let mut width = 0;
+    let mut last = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let (c, len) = decode(bytes, i);
+        i += len;
+
+        last = if c == EMOJI_PRESENTATION && last == 1 {
+            1
+        } else if is_zero_width(c) || c < 0x20 {
+            0
+        } else if is_wide(c) {
+            2
+        } else {
+            1
+        };
+        width += last;
+    }
+
+    width
+}
+
+impl SpinnerInfo {
+    /// Works out the info for the given frames
+    const fn of(frames: &[&str]) -> Self {
+        let mut info = Self {
+            width: 0,
+            uniform_width: true,
+            ascii: true,
+            emoji: false,
+            braille: false,
+        };
+
+        let mut f = 0;
+        while f < frames.len() {
+            let width = frame_width(frames[f]);
+            if f > 0 && width != info.width {
+                info.uniform_width = false;
+            }
+            if width > info.width {
+                info.width = width;
+            }
+
+            let bytes = frames[f].as_bytes();
+            let mut i = 0;
+            while i < bytes.len() {
+                let (c, len) = decode(bytes, i);
+                i += len;
+
+                info.ascii &= c < 0x80;
+                info.emoji |= is_emoji(c) || c == EMOJI_PRESENTATION;
+                info.braille |= matches!(c, 0x2800..=0x28FF);
+            }
+
+            f += 1;
+        }
+
+        info
+    }
+}
+
+impl Spinners {
+    /// Gets the info about the frames of the spinner, see [`SpinnerInfo`]
+    pub const fn info(&self) -> SpinnerInfo {
+        SpinnerInfo::of(self.get_frames())
+    }
+
+    /// The width of the widest frame, in terminal columns
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::Spinners;
+    ///
+    /// assert_eq!(Spinners::Dots.width(), 1);
+    /// assert_eq!(Spinners::Arrow3.width(), 5);
+    /// ```
+    pub const fn width(&self) -> usize {
+        self.info().width
+    }
+
+    /// Whether every frame is plain ASCII, so it can be shown on any terminal
+    pub const fn is_ascii(&self) -> bool {
+        self.info().ascii
+    }
+}
@@ -0,0 +1,254 @@
+use std::{
+    io::{self, Read, Write},
+    time::{Duration, Instant},
+};
+
+use crate::Spinner;
+
+/// How often the spinner message is refreshed while bytes are flowing
+const UPDATE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Formats a byte count using binary units, e.g. `12.4 MiB`
+fn format_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+    if bytes < 1024.0 {
+        return format!("{} B", bytes as u64);
+    }
+
+    let mut size = bytes / 1024.0;
+    let mut unit = UNITS[0];
+
+    for next in &UNITS[1..] {
+        if size < 1024.0 {
+            break;
+        }
+
+        size /= 1024.0;
+        unit = next;
+    }
+
+    format!("{:.1} {}", size, unit)
+}
+
+/// Keeps track of the bytes transferred and renders them into the spinner message
+#[derive(Debug)]
+struct Progress {
+    spinner: Spinner,
+    label: String,
+    bytes: u64,
+    started: Instant,
+    last_update: Option<Instant>,
+    finished: bool,
+}
+
+impl Progress {
+    fn new(mut spinner: Spinner) -> Self {
+        let label = spinner.get_message();
+
+        if !spinner.is_running() {
+            spinner.start();
+        }
+
+        Self {
+            spinner,
+            label,
+            bytes: 0,
+            started: Instant::now(),
+            last_update: None,
+            finished: false,
+        }
+    }
+
+    fn message(&self) -> String {
+        let elapsed = self.started.elapsed().as_secs_f64();
+        let rate = if elapsed > 0.0 {
+            self.bytes as f64 / elapsed
+        } else {
+            0.0
+        };
+
+        let progress = format!(
+            "{} @ {}/s",
+            format_bytes(self.bytes as f64),
+            format_bytes(rate)
+        );
+
+        if self.label.is_empty() {
+            progress
+        } else {
+            format!("{} {}", self.label, progress)
+        }
+    }
+
+    fn add(&mut self, bytes: usize) {
+        self.bytes += bytes as u64;
+
+        let now = Instant::now();
+        let due = match self.last_update {
+            Some(last) => now.duration_since(last) >= UPDATE_INTERVAL,
+            None => true,
+        };
+
+        if due {
+            self.last_update = Some(now);
+            let message = self.message();
+            self.spinner.set_message(message);
+        }
+    }
+
+    fn finish(&mut self) {
+        if self.finished {
+            return;
+        }
+
+        self.finished = true;
+        let message = self.message();
+        self.spinner.set_message(message);
+        self.spinner.stop_with_success();
+    }
+}
+
+/// A [`Read`] wrapper that displays the bytes read through a [`Spinner`]
+///
+/// The spinner message is updated with the amount of bytes read and the current throughput, e.g. `Downloading 12.4 MiB @ 3.1 MiB/s`.
+/// Any message already set on the spinner is kept as a label in front of the progress.
+///
+/// The spinner is started when the reader is created (if it isn't running already) and finished once the inner reader reaches EOF.
+///
+/// # Example:
+///
+/// ```
+/// use std::io;
+/// use spinners_rs::{Spinner, SpinnerReader, Spinners};
+///
+/// let data = vec![0u8; 64 * 1024];
+///
+/// let sp = Spinner::new(Spinners::Dots, "Copying");
+/// let mut reader = SpinnerReader::new(data.as_slice(), sp);
+/// let mut out = Vec::new();
+///
+/// io::copy(&mut reader, &mut out).unwrap();
+///
+/// assert_eq!(reader.bytes_read(), 64 * 1024);
+/// ```
+#[derive(Debug)]
+pub struct SpinnerReader<R> {
+    inner: R,
+    progress: Progress,
+}
+
+impl<R: Read> SpinnerReader<R> {
+    /// Wraps the given reader, reporting progress through the given spinner
+    pub fn new(inner: R, spinner: Spinner) -> Self {
+        Self {
+            inner,
+            progress: Progress::new(spinner),
+        }
+    }
+
+    /// The total amount of bytes read so far
+    pub fn bytes_read(&self) -> u64 {
+        self.progress.bytes
+    }
+
+    /// Finishes the spinner, even if EOF hasn't been reached yet
+    pub fn finish(&mut self) {
+        self.progress.finish();
+    }
+
+    /// Gets a reference to the underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Finishes the spinner and returns the underlying reader
+    pub fn into_inner(mut self) -> R {
+        self.finish();
+        self.inner
+    }
+}
+
+impl<R: Read> Read for SpinnerReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+
+        if read == 0 && !buf.is_empty() {
+            self.progress.finish();
+        } else {
+            self.progress.add(read);
+        }
+
+        Ok(read)
+    }
+}
+
+/// A [`Write`] wrapper that displays the bytes written through a [`Spinner`]
+///
+/// Works the same way as [`SpinnerReader`], but as there is no EOF when writing, the spinner is finished by [`SpinnerWriter::finish`] or [`SpinnerWriter::into_inner`].
+///
+/// # Example:
+///
+/// ```
+/// use std::io;
+/// use spinners_rs::{Spinner, SpinnerWriter, Spinners};
+///
+/// let data = vec![0u8; 64 * 1024];
+///
+/// let sp = Spinner::new(Spinners::Dots, "Saving");
+/// let mut writer = SpinnerWriter::new(Vec::new(), sp);
+///
+/// io::copy(&mut data.as_slice(), &mut writer).unwrap();
+///
+/// let out = writer.into_inner();
+/// assert_eq!(out.len(), 64 * 1024);
+/// ```
+#[derive(Debug)]
+pub struct SpinnerWriter<W> {
+    inner: W,
+    progress: Progress,
+}
+
+impl<W: Write> SpinnerWriter<W> {
+    /// Wraps the given writer, reporting progress through the given spinner
+    pub fn new(inner: W, spinner: Spinner) -> Self {
+        Self {
+            inner,
+            progress: Progress::new(spinner),
+        }
+    }
+
+    /// The total amount of bytes written so far
+    pub fn bytes_written(&self) -> u64 {
+        self.progress.bytes
+    }
+
+    /// Finishes the spinner
+    pub fn finish(&mut self) {
+        self.progress.finish();
+    }
+
+    /// Gets a reference to the underlying writer
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Finishes the spinner and returns the underlying writer
+    pub fn into_inner(mut self) -> W {
+        self.finish();
+        self.inner
+    }
+}
+
+impl<W: Write> Write for SpinnerWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.progress.add(written);
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -20,12 +20,87 @@
 //!
 //! thread::sleep(Duration::from_secs(3));
 //! ```
+//!
+//! ## Environment variables
+//!
+//! Users can turn spinners off (`SPINNERS_RS=off`), pick a different one (`SPINNERS_RS_STYLE=line`) slow them down (`SPINNERS_RS_INTERVAL_MULTIPLIER=2`) or show them without motion for screen readers (`SPINNERS_RS_ACCESSIBLE=1`) without changing the program.
+//! See [`Spinner::set_env_overrides`] for the details, and for turning this off.
+//!
+//! ## Testing
+//!
+//! Give a spinner a [`ManualClock`] and an [`Output::writer`] to check exactly what it draws, moving time along with [`ManualClock::advance`] instead of sleeping.
+//! A [`TestTerminal`] plays out what was written the way a terminal would, so tests can check what was left on screen.
+//!
+//! ## Features
+//!
+//! - `serde`: Implements `Serialize` and `Deserialize` for [`Spinners`], [`CustomSpinner`] and [`SpinnerBuilder`] (along with the options it takes), so spinners can be set up from config files.
 
 // All of the actual code is stored in other files
 // This is the main file that includes the code for the Spinner struct
 mod spinner;
+// The builder for configuring spinners
+mod builder;
+// A cloneable handle for updating a spinner from other threads
+mod handle;
 // This is the file that includes all the data for the spinners like the spinner enum and the frames
 mod spinners;
+// Grouping the spinners by what they look like
+mod category;
+// What the frames of each spinner are made of
+mod info;
+// Custom spinners put together from the built in ones
+mod style;
+// Swapping out spinners that the terminal can't show
+mod fallback;
+// Letting users change spinners through environment variables
+mod env;
+// Heartbeat lines on CI and the accessible mode, instead of animation
+mod mode;
+// The error type returned by the fallible spinner methods
+mod error;
+// Parsing spinner names from config files and command line flags
+mod parse;
+// The order in which frames are shown
+mod animation;
+// Where spinners are drawn
+mod output;
+// Where the frame is placed relative to the message
+mod placement;
+// Laying out the spinner line with placeholders
+mod template;
+// The color of the spinner frame
+mod color;
+// Where spinners get the time from, including a manual clock for tests
+mod clock;
+// A virtual terminal for checking what spinners leave on screen
+mod terminal;
+// The time based frame state shared by the render thread and manually ticked spinners
+mod state;
+// The single render thread that draws every running spinner
+mod scheduler;
+// Reader and writer wrappers that report the bytes transferred through a spinner
+mod io;
+// Running subprocesses behind a spinner
+mod process;
+// Made up environments for testing what is read from environment variables
+#[cfg(test)]
+mod test_env;
 
+pub use animation::*;
+pub use builder::*;
+pub use category::*;
+pub use clock::*;
+pub use color::*;
+pub use env::{ENV_ACCESSIBLE, ENV_ENABLED, ENV_INTERVAL_MULTIPLIER, ENV_STYLE};
+pub use error::*;
+pub use fallback::*;
+pub use handle::*;
+pub use info::*;
+pub use io::*;
+pub use mode::{is_ci, Mode, ACCESSIBLE_INDICATOR, DEFAULT_HEARTBEAT};
+pub use output::*;
+pub use placement::*;
 pub use spinner::*;
 pub use spinners::*;
+pub use style::*;
+pub use terminal::*;
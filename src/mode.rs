@@ -0,0 +1,191 @@
+use std::{env, time::Duration};
+
+/// The time between heartbeat lines when running on CI, in milliseconds
+pub const DEFAULT_HEARTBEAT: u64 = 30_000;
+
+/// The indicator shown in place of the frames by [`Mode::Accessible`]
+pub const ACCESSIBLE_INDICATOR: &str = "*";
+
+/// How a running spinner is shown by the render thread
+///
+/// Set with [`Spinner::set_mode`](crate::Spinner::set_mode). Drawing the spinner yourself with [`Spinner::tick`](crate::Spinner::tick) always animates it.
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::{Mode, Spinner, Spinners};
+///
+/// let mut sp = Spinner::builder()
+///     .spinner(Spinners::Dots)
+///     .message("Compiling")
+///     .mode(Mode::Heartbeat(60_000))
+///     .start()
+///     .unwrap();
+///
+/// // Prints "Compiling", then "still running: Compiling (1m0s)" every minute
+///
+/// sp.stop_with_success();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum Mode {
+    /// [`Mode::Heartbeat`] every [`DEFAULT_HEARTBEAT`] milliseconds on CI (see [`is_ci`]), [`Mode::Animated`] everywhere else
+    #[default]
+    Auto,
+    /// Redraw the spinner line in place as the frames change
+    Animated,
+    /// Print the message once, then a line every given amount of milliseconds to show it is still running
+    ///
+    /// Nothing is redrawn in place, which keeps CI logs readable while still showing signs of life to CI systems that time out jobs without output.
+    /// The final line written when the spinner is stopped goes on a line of its own.
+    Heartbeat(u64),
+    /// Print a static line instead of animating, for screen readers and anyone who prefers less motion
+    ///
+    /// The line is printed again whenever the message changes, and [`Spinner::stop_with_success`](crate::Spinner::stop_with_success) and [`Spinner::stop_with_failure`](crate::Spinner::stop_with_failure) say `done` and `failed` instead of showing a symbol.
+    /// Users can also turn this on with an environment variable, see [`Spinner::set_env_overrides`](crate::Spinner::set_env_overrides).
+    ///
+    /// ```
+    /// use spinners_rs::{Mode, Spinner, Spinners};
+    ///
+    /// let mut sp = Spinner::builder()
+    ///     .spinner(Spinners::Dots)
+    ///     .message("Compiling")
+    ///     .mode(Mode::Accessible)
+    ///     .start()
+    ///     .unwrap();
+    ///
+    /// // Prints "* Compiling", then "* Linking" and finally "Linking: done"
+    /// sp.set_message("Linking");
+    /// sp.stop_with_success();
+    /// ```
+    Accessible,
+}
+
+impl Mode {
+    /// Works out what [`Mode::Auto`] stands for in the current environment
+    pub(crate) fn resolve(self) -> Self {
+        match self {
+            Mode::Auto if is_ci() => Mode::Heartbeat(DEFAULT_HEARTBEAT),
+            Mode::Auto => Mode::Animated,
+            mode => mode,
+        }
+    }
+
+    /// Whether the spinner line is redrawn in place
+    pub(crate) fn is_animated(self) -> bool {
+        matches!(self, Mode::Auto | Mode::Animated)
+    }
+
+    /// Whether the spinner is drawn again as soon as something about it changes
+    pub(crate) fn redraws_on_change(self, message_changed: bool) -> bool {
+        match self {
+            Mode::Auto | Mode::Animated => true,
+            Mode::Heartbeat(_) => false,
+            Mode::Accessible => message_changed,
+        }
+    }
+}
+
+/// Whether the program seems to be running on a CI system
+///
+/// This checks for the environment variables set by GitHub Actions, GitLab CI, Azure Pipelines, Jenkins, CircleCI, Travis, Buildkite and most others, which includes the general `CI` variable.
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::is_ci;
+///
+/// // Colors are usually lost in CI logs
+/// let message = if is_ci() {
+///     "Running the test suite".to_string()
+/// } else {
+///     "\x1b[1mRunning the test suite\x1b[0m".to_string()
+/// };
+///
+/// println!("{}", message);
+/// ```
+pub fn is_ci() -> bool {
+    detect_ci(|name| env::var(name).ok())
+}
+
+/// The detection behind [`is_ci`], with the environment passed in
+fn detect_ci(var: impl Fn(&str) -> Option<String>) -> bool {
+    let set = |name| var(name).is_some_and(|value| !value.is_empty());
+
+    let general =
+        var("CI").is_some_and(|value| !matches!(value.to_lowercase().as_str(), "" | "0" | "false"));
+
+    general
+        || [
+            "GITHUB_ACTIONS",
+            "GITLAB_CI",
+            "TF_BUILD",
+            "JENKINS_URL",
+            "CIRCLECI",
+            "TRAVIS",
+            "BUILDKITE",
+            "TEAMCITY_VERSION",
+            "APPVEYOR",
+            "BITBUCKET_BUILD_NUMBER",
+            "DRONE",
+            "CODEBUILD_BUILD_ID",
+        ]
+        .iter()
+        .any(|name| set(*name))
+}
+
+/// Formats a duration the way heartbeat lines show it, such as `2m30s`
+pub(crate) fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+
+    match (secs / 3600, secs / 60 % 60, secs % 60) {
+        (0, 0, s) => format!("{}s", s),
+        (0, m, s) => format!("{}m{}s", m, s),
+        (h, m, s) => format!("{}h{}m{}s", h, m, s),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_env::lookup;
+
+    /// Detects CI in an environment made of the given variables
+    fn ci_in(vars: &[(&str, &str)]) -> bool {
+        detect_ci(lookup(vars))
+    }
+
+    #[test]
+    fn the_general_variable_can_be_turned_off() {
+        assert!(ci_in(&[("CI", "true")]));
+        assert!(ci_in(&[("CI", "1")]));
+        assert!(!ci_in(&[("CI", "false")]));
+        assert!(!ci_in(&[("CI", "0")]));
+        assert!(!ci_in(&[("CI", "")]));
+    }
+
+    #[test]
+    fn recognises_ci_systems_by_their_own_variables() {
+        assert!(ci_in(&[("GITHUB_ACTIONS", "true")]));
+        assert!(ci_in(&[("JENKINS_URL", "https://jenkins.example.com")]));
+        assert!(ci_in(&[("CI", "false"), ("GITLAB_CI", "true")]));
+        assert!(!ci_in(&[("TRAVIS", "")]));
+    }
+
+    #[test]
+    fn local_terminals_are_not_ci() {
+        assert!(!ci_in(&[]));
+        assert!(!ci_in(&[("TERM", "xterm-256color"), ("HOME", "/home/me")]));
+    }
+
+    #[test]
+    fn elapsed_times_leave_out_empty_units() {
+        assert_eq!(format_elapsed(Duration::from_secs(5)), "5s");
+        assert_eq!(format_elapsed(Duration::from_secs(150)), "2m30s");
+        assert_eq!(format_elapsed(Duration::from_secs(3600)), "1h0m0s");
+    }
+}
@@ -0,0 +1,83 @@
+use std::{
+    fmt,
+    io::{self, stderr, stdout, Write},
+    sync::{Arc, Mutex},
+};
+
+/// Where a spinner is drawn
+///
+/// Set with [`SpinnerBuilder::output`](crate::SpinnerBuilder::output).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum Output {
+    /// Draw the spinner to stdout
+    #[default]
+    Stdout,
+    /// Draw the spinner to stderr, leaving stdout free for the actual output of the program
+    Stderr,
+    /// Draw the spinner to a writer of your own, see [`Output::writer`]
+    ///
+    /// This can't be read from or written to config files.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Writer(SharedWriter),
+}
+
+impl Output {
+    /// Draws the spinner to the given writer, such as a log file or a buffer to check in tests
+    ///
+    /// The writer is shared by every spinner given a clone of this output.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Output, Spinner, Spinners};
+    /// use std::fs::File;
+    ///
+    /// let log = File::create(std::env::temp_dir().join("spinner.log")).unwrap();
+    ///
+    /// let mut sp = Spinner::builder()
+    ///     .spinner(Spinners::Dots)
+    ///     .message("Doing some cool things...")
+    ///     .output(Output::writer(log))
+    ///     .start()
+    ///     .unwrap();
+    ///
+    /// sp.stop_with_success();
+    /// ```
+    pub fn writer(writer: impl Write + Send + 'static) -> Self {
+        Output::Writer(SharedWriter(Arc::new(Mutex::new(writer))))
+    }
+
+    /// Runs the given function with a lock on the output
+    pub(crate) fn with<T>(&self, f: impl FnOnce(&mut dyn Write) -> io::Result<T>) -> io::Result<T> {
+        match self {
+            Output::Stdout => f(&mut stdout().lock()),
+            Output::Stderr => f(&mut stderr().lock()),
+            Output::Writer(writer) => f(&mut *writer.0.lock().unwrap_or_else(|e| e.into_inner())),
+        }
+    }
+}
+
+/// A writer given to [`Output::writer`]
+///
+/// Outputs are equal when they share the same writer.
+#[derive(Clone)]
+pub struct SharedWriter(Arc<Mutex<dyn Write + Send>>);
+
+impl PartialEq for SharedWriter {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SharedWriter {}
+
+impl fmt::Debug for SharedWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SharedWriter").finish()
+    }
+}
@@ -0,0 +1,98 @@
+use std::{convert::TryFrom, str::FromStr};
+
+use strum::IntoEnumIterator;
+
+use crate::{ParseSpinnerError, Spinners};
+
+/// The most spinners suggested when a name doesn't exist
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Lowercases the name and drops any separators, so every casing style compares the same
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, '-' | '_' | ' ' | '.'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// The amount of single character edits it takes to turn one name into the other
+fn distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+
+    row[b.len()]
+}
+
+impl FromStr for Spinners {
+    type Err = ParseSpinnerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = normalize(s);
+
+        if let Some(spinner) =
+            Spinners::iter().find(|spinner| normalize(&spinner.to_string()) == name)
+        {
+            return Ok(spinner);
+        }
+
+        // Anything further away than this is more likely a different word than a typo
+        let max_distance = (name.chars().count() / 3).max(2);
+
+        let mut close = Spinners::iter()
+            .map(|spinner| (distance(&name, &normalize(&spinner.to_string())), spinner))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .collect::<Vec<_>>();
+
+        // The sort is stable, so ties stay in the same order as the enum
+        close.sort_by_key(|(distance, _)| *distance);
+
+        Err(ParseSpinnerError {
+            name: s.to_string(),
+            suggestions: close
+                .into_iter()
+                .take(MAX_SUGGESTIONS)
+                .map(|(_, spinner)| spinner)
+                .collect(),
+        })
+    }
+}
+
+impl TryFrom<&str> for Spinners {
+    type Error = ParseSpinnerError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Spinners are stored by the same name they are displayed with, and read back as leniently as they are parsed
+#[cfg(feature = "serde")]
+impl serde::Serialize for Spinners {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Spinners {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
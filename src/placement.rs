@@ -0,0 +1,106 @@
+/// Which side of the message the spinner frame is drawn on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum Side {
+    /// `⠋ Loading...`
+    #[default]
+    Left,
+    /// `Loading... ⠋`
+    Right,
+}
+
+/// Where the spinner frame is placed relative to the message
+///
+/// Set with [`Spinner::set_placement`](crate::Spinner::set_placement). This is also used for the symbol shown by [`Spinner::stop_with_symbol`](crate::Spinner::stop_with_symbol).
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::{Placement, Spinner, Spinners, TestTerminal};
+///
+/// let mut sp = Spinner::new(Spinners::Line, "Loading...");
+/// sp.set_placement(Placement::right().brackets("[", "]"));
+///
+/// let mut terminal = TestTerminal::new();
+/// sp.render_to(&mut terminal).unwrap();
+///
+/// assert_eq!(terminal.screen(), "Loading... [-]");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, rename_all = "camelCase")
+)]
+pub struct Placement {
+    side: Side,
+    separator: String,
+    brackets: Option<(String, String)>,
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Self::left()
+    }
+}
+
+impl Placement {
+    /// The frame before the message, separated by a space
+    pub fn left() -> Self {
+        Self {
+            side: Side::Left,
+            separator: " ".to_string(),
+            brackets: None,
+        }
+    }
+
+    /// The frame after the message, separated by a space
+    pub fn right() -> Self {
+        Self {
+            side: Side::Right,
+            ..Self::left()
+        }
+    }
+
+    /// Sets the text between the frame and the message, instead of a single space
+    pub fn separator(mut self, separator: impl std::fmt::Display) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Wraps the frame in the given text, such as `[` and `]`
+    pub fn brackets(mut self, open: impl std::fmt::Display, close: impl std::fmt::Display) -> Self {
+        self.brackets = Some((open.to_string(), close.to_string()));
+        self
+    }
+
+    /// Which side of the message the frame is drawn on
+    pub fn side(&self) -> Side {
+        self.side
+    }
+
+    /// Puts the frame (or a symbol in its place) and the message together
+    pub(crate) fn join(&self, frame: &str, message: &str) -> String {
+        let frame = match &self.brackets {
+            Some((open, close)) if !frame.is_empty() => format!("{}{}{}", open, frame, close),
+            _ => frame.to_string(),
+        };
+
+        if message.is_empty() {
+            return frame;
+        }
+
+        if frame.is_empty() {
+            return message.to_string();
+        }
+
+        match self.side {
+            Side::Left => format!("{}{}{}", frame, self.separator, message),
+            Side::Right => format!("{}{}{}", message, self.separator, frame),
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    process::{Command, Output, Stdio},
+    sync::mpsc::{channel, Sender},
+    thread,
+};
+
+use crate::Spinner;
+
+/// A single line of output, tagged with the stream it was written to
+enum Line {
+    Stdout(Vec<u8>),
+    Stderr(Vec<u8>),
+}
+
+/// Formats the program and arguments of a command the way they would be typed in a POSIX shell
+///
+/// Arguments with spaces or other characters the shell would treat specially are single quoted.
+fn command_line(command: &Command) -> String {
+    std::iter::once(command.get_program())
+        .chain(command.get_args())
+        .map(|arg| quote(&arg.to_string_lossy()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Quotes an argument for a POSIX shell, if it needs it
+fn quote(arg: &str) -> String {
+    let plain = |ch: char| ch.is_alphanumeric() || "-_./=:,+@%".contains(ch);
+
+    if !arg.is_empty() && arg.chars().all(plain) {
+        return arg.to_string();
+    }
+
+    format!("'{}'", arg.replace('\'', "'\\''"))
+}
+
+/// The part of an output line a terminal would leave showing, without escape sequences or other control characters
+///
+/// Progress output redraws itself with `\r`, so only the text after the last one is kept.
+fn visible_text(line: &[u8]) -> String {
+    let text = String::from_utf8_lossy(line);
+    let text = text.trim_end_matches(['\r', '\n']);
+    let text = text.rsplit('\r').next().unwrap_or_default();
+
+    let mut visible = String::new();
+    let mut chars = text.chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            // Control sequences run until their final byte, other escapes are a single character long
+            '\x1b' => {
+                if chars.next() == Some('[') {
+                    for ch in chars.by_ref() {
+                        if ('@'..='~').contains(&ch) {
+                            break;
+                        }
+                    }
+                }
+            }
+            '\t' => visible.push(' '),
+            ch if ch.is_control() => {}
+            ch => visible.push(ch),
+        }
+    }
+
+    visible.trim().to_string()
+}
+
+/// Reads the given pipe line by line on a separate thread, sending each line back over the channel
+fn forward_lines<R, F>(pipe: R, sender: Sender<Line>, wrap: F) -> thread::JoinHandle<()>
+where
+    R: Read + Send + 'static,
+    F: Fn(Vec<u8>) -> Line + Send + 'static,
+{
+    thread::spawn(move || {
+        let mut reader = BufReader::new(pipe);
+
+        loop {
+            let mut line = Vec::new();
+
+            match reader.read_until(b'\n', &mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    if sender.send(wrap(line)).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Prints the captured output of a failed command to stderr, below the spinner line
+fn echo(combined: &[u8]) -> io::Result<()> {
+    let mut err = io::stderr().lock();
+    writeln!(err)?;
+    err.write_all(combined)?;
+    err.flush()
+}
+
+impl Spinner {
+    /// Runs the given command while the spinner is displayed
+    ///
+    /// The command line is used as the spinner message, and if `show_output` is set the last line the command wrote to stdout or stderr is shown alongside it.
+    /// Only the text a terminal would leave showing is used: what comes after the last `\r` in the line, without colors or other control characters.
+    ///
+    /// Once the command exits the spinner is stopped with [`Spinner::stop_with_success`] or [`Spinner::stop_with_failure`] depending on the exit status.
+    /// If the command failed, everything it wrote is printed to stderr. The output is returned even if printing it fails.
+    ///
+    /// The captured output is returned in the same way as [`Command::output`].
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use std::process::Command;
+    /// use spinners_rs::{Spinners, Spinner};
+    ///
+    /// let mut sp: Spinner = Spinners::Dots.into();
+    ///
+    /// let output = sp
+    ///     .run_command(Command::new("cargo").arg("--version"), true)
+    ///     .unwrap();
+    ///
+    /// assert!(output.status.success());
+    /// ```
+    pub fn run_command(&mut self, command: &mut Command, show_output: bool) -> io::Result<Output> {
+        let command_line = command_line(command);
+
+        let mut child = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        self.set_message(&command_line);
+        if !self.is_running() {
+            self.start();
+        }
+
+        let (sender, recv) = channel::<Line>();
+        let mut readers = Vec::new();
+
+        if let Some(stdout) = child.stdout.take() {
+            readers.push(forward_lines(stdout, sender.clone(), Line::Stdout));
+        }
+
+        if let Some(stderr) = child.stderr.take() {
+            readers.push(forward_lines(stderr, sender.clone(), Line::Stderr));
+        }
+
+        // Drop our own sender so the loop below ends once both pipes are closed
+        drop(sender);
+
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        let mut combined = Vec::new();
+
+        for line in recv {
+            let line = match line {
+                Line::Stdout(line) => {
+                    stdout.extend_from_slice(&line);
+                    line
+                }
+                Line::Stderr(line) => {
+                    stderr.extend_from_slice(&line);
+                    line
+                }
+            };
+
+            if show_output {
+                let text = visible_text(&line);
+
+                if !text.is_empty() {
+                    self.set_message(format!("{}: {}", command_line, text));
+                }
+            }
+
+            combined.extend_from_slice(&line);
+        }
+
+        for reader in readers {
+            let _ = reader.join();
+        }
+
+        let status = child.wait()?;
+
+        self.set_message(&command_line);
+
+        if status.success() {
+            self.stop_with_success();
+        } else {
+            self.stop_with_failure();
+
+            // The caller still needs the output and exit status if stderr can't be written to
+            let _ = echo(&combined);
+        }
+
+        Ok(Output {
+            status,
+            stdout,
+            stderr,
+        })
+    }
+}
@@ -0,0 +1,389 @@
+use std::{
+    borrow::Cow,
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    io,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc::{channel, Receiver, RecvTimeoutError, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::Instant,
+};
+
+use crate::{state::State, Error, Event, ManualClock};
+
+/// Used to give every registered spinner a unique id
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// The sender half of the channel to the render thread, if it is running
+///
+/// The render thread only exits while holding this lock, so a spinner can never be registered with a thread that is about to exit.
+static SCHEDULER: Mutex<Option<Sender<Message>>> = Mutex::new(None);
+
+/// Where the render thread leaves a write error for the spinner handle to pick up
+type ErrorSlot = Arc<Mutex<Option<io::Error>>>;
+
+/// The messages sent from spinner handles to the render thread
+#[derive(Debug)]
+pub(crate) enum Message {
+    /// Start drawing a new spinner
+    Start(u64, Box<State>, ErrorSlot),
+    /// An event for an already running spinner, optionally replying once it has been handled
+    Event(u64, Event, Option<Sender<()>>),
+}
+
+/// What draws a registered spinner
+#[derive(Debug, Clone)]
+enum Target {
+    /// The render thread, in real time
+    Thread(Sender<Message>),
+    /// A manual clock, as it is advanced
+    Manual(ManualClock),
+}
+
+/// A spinner registered with the render thread
+#[derive(Debug, Clone)]
+pub(crate) struct Registration {
+    id: u64,
+    target: Target,
+    error: ErrorSlot,
+}
+
+impl Registration {
+    /// Takes the write error reported by the render thread, if there is one
+    fn take_error(&self) -> Result<(), Error> {
+        match self.error.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            Some(e) => Err(Error::Io(e)),
+            None => Ok(()),
+        }
+    }
+
+    /// Sends an event to the render thread for this spinner
+    ///
+    /// Any write error that happened since the last event is returned instead.
+    pub fn send(&self, event: Event) -> Result<(), Error> {
+        self.take_error()?;
+
+        match &self.target {
+            Target::Thread(sender) => sender
+                .send(Message::Event(self.id, event, None))
+                .map_err(|_| Error::Disconnected),
+            Target::Manual(clock) => {
+                clock.handle(Message::Event(self.id, event, None));
+                Ok(())
+            }
+        }
+    }
+
+    /// Sends an event to the render thread for this spinner, waiting until it has been handled
+    ///
+    /// Once a [`Event::Stop`] or [`Event::Pause`] has been handled, it is safe to write over the spinner line.
+    pub fn send_and_wait(&self, event: Event) -> Result<(), Error> {
+        self.take_error()?;
+
+        match &self.target {
+            Target::Thread(sender) => {
+                let (ack, done) = channel();
+
+                sender
+                    .send(Message::Event(self.id, event, Some(ack)))
+                    .map_err(|_| Error::Disconnected)?;
+
+                done.recv().map_err(|_| Error::Disconnected)?;
+            }
+            // Manual clocks handle events before returning
+            Target::Manual(clock) => clock.handle(Message::Event(self.id, event, None)),
+        }
+
+        self.take_error()
+    }
+}
+
+/// Registers a spinner with the render thread, starting the thread if there isn't one running
+///
+/// Spinners using a [`ManualClock`] are registered with the clock instead.
+pub(crate) fn register(state: State) -> Registration {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    let error = ErrorSlot::default();
+
+    if let Some(clock) = state.clock.as_manual() {
+        let clock = clock.clone();
+        clock.handle(Message::Start(id, Box::new(state), error.clone()));
+
+        return Registration {
+            id,
+            target: Target::Manual(clock),
+            error,
+        };
+    }
+
+    let mut scheduler = SCHEDULER.lock().unwrap_or_else(|e| e.into_inner());
+    let mut message = Message::Start(id, Box::new(state), error.clone());
+
+    loop {
+        if let Some(sender) = &*scheduler {
+            match sender.send(message) {
+                Ok(()) => {
+                    return Registration {
+                        id,
+                        target: Target::Thread(sender.clone()),
+                        error,
+                    }
+                }
+                // The render thread died without cleaning up after itself, so start a new one
+                Err(e) => message = e.0,
+            }
+        }
+
+        let (sender, recv) = channel();
+        thread::spawn(move || run(recv));
+        *scheduler = Some(sender);
+    }
+}
+
+/// A spinner being drawn by the render thread
+struct Entry {
+    state: State,
+    /// When this spinner is next due to be drawn
+    next: Instant,
+    error: ErrorSlot,
+}
+
+impl Entry {
+    /// Hands the error over to the spinner handle
+    fn report(&self, e: io::Error) {
+        *self.error.lock().unwrap_or_else(|e| e.into_inner()) = Some(e);
+    }
+}
+
+/// The state of the render thread, or of a [`ManualClock`]
+///
+/// Deadlines are kept in a min-heap, so the thread only wakes up when a spinner needs to be drawn or a message comes in.
+/// Entries for spinners that were stopped or rescheduled are left in the heap and skipped once they come up.
+pub(crate) struct Scheduler {
+    spinners: HashMap<u64, Entry>,
+    deadlines: BinaryHeap<Reverse<(Instant, u64)>>,
+    /// Whether the times passed in are real time, with each spinner reading its own clock
+    real_time: bool,
+}
+
+impl Scheduler {
+    /// A scheduler for spinners that share the clock whose times are passed in, as with a [`ManualClock`]
+    pub fn new() -> Self {
+        Self {
+            spinners: HashMap::new(),
+            deadlines: BinaryHeap::new(),
+            real_time: false,
+        }
+    }
+
+    /// A scheduler driven by real time, for spinners that may each have a clock of their own
+    ///
+    /// Spinners are drawn as their own clock says, while deadlines are kept in real time so the thread knows how long to sleep.
+    fn real_time() -> Self {
+        Self {
+            real_time: true,
+            ..Self::new()
+        }
+    }
+
+    /// Applies a message at the given time
+    pub fn handle(&mut self, message: Message, now: Instant) {
+        let real_time = self.real_time;
+
+        let (id, event, ack) = match message {
+            Message::Start(id, state, error) => {
+                let hidden = state.output.with(|w| state.show_cursor(w, false));
+
+                let entry = Entry {
+                    state: *state,
+                    next: now,
+                    error,
+                };
+
+                if let Err(e) = hidden {
+                    entry.report(e);
+                    return;
+                }
+
+                self.spinners.insert(id, entry);
+                self.deadlines.push(Reverse((now, id)));
+                return;
+            }
+            Message::Event(id, event, ack) => (id, event, ack),
+        };
+
+        if let Some(entry) = self.spinners.get_mut(&id) {
+            let state = &mut entry.state;
+            let now = local_time(state, now, real_time);
+            let mut result = Ok(());
+            let mut message_changed = false;
+            let mut stopped = false;
+
+            match event {
+                Event::Stop => {
+                    stopped = true;
+                    result = state.output.with(|w| state.show_cursor(w, true));
+                }
+                Event::SetMessage(message) => {
+                    message_changed = state.message != message;
+                    state.message = message;
+                }
+                Event::SetPrefix(prefix) => state.prefix = prefix,
+                Event::SetSuffix(suffix) => state.suffix = suffix,
+                Event::SetPlacement(placement) => state.placement = placement,
+                Event::SetInterval(interval) => state.set_interval(interval, now),
+                Event::SetFrames(frames) => {
+                    let frames = frames.iter().map(|frame| Cow::Borrowed(*frame)).collect();
+                    state.set_frames(frames, now);
+                }
+                Event::SetCustomFrames(frames) => state.set_frames(frames, now),
+                Event::SetAnimation(animation) => state.set_animation(animation, now),
+                Event::Pause => {
+                    state.pause(now);
+                    result = state.output.with(|w| {
+                        state.clear(w)?;
+                        state.show_cursor(w, true)
+                    });
+                }
+                Event::Resume => {
+                    state.resume(now);
+                    result = state.output.with(|w| state.show_cursor(w, false));
+                }
+            }
+
+            if stopped {
+                if let Some(entry) = self.spinners.remove(&id) {
+                    if let Err(e) = result {
+                        entry.report(e);
+                    }
+                }
+            } else if let Err(e) = result {
+                self.fail(id, e);
+            } else if entry.state.paused.is_none()
+                && entry.state.mode.redraws_on_change(message_changed)
+            {
+                // Redraw straight away to show the change
+                entry.next = now;
+                self.deadlines.push(Reverse((now, id)));
+            }
+        }
+
+        if let Some(ack) = ack {
+            let _ = ack.send(());
+        }
+    }
+
+    /// Draws every spinner whose frame is due at the given time
+    pub fn draw(&mut self, now: Instant) {
+        let real_time = self.real_time;
+
+        while let Some(&Reverse((due, id))) = self.deadlines.peek() {
+            if due > now {
+                break;
+            }
+
+            self.deadlines.pop();
+
+            let entry = match self.spinners.get_mut(&id) {
+                // Skip entries that have since been rescheduled, and paused spinners
+                Some(entry) if entry.next == due && entry.state.paused.is_none() => entry,
+                _ => continue,
+            };
+
+            let state = &entry.state;
+            let local = local_time(state, now, real_time);
+
+            if let Err(e) = state.output.with(|w| state.draw(w, local)) {
+                self.fail(id, e);
+                continue;
+            }
+
+            if let Some(next) = entry.state.next_draw(local) {
+                // The deadline is kept in the scheduler's time, however far the spinner's clock is from it
+                let next = now + next.saturating_duration_since(local);
+
+                entry.next = next;
+                self.deadlines.push(Reverse((next, id)));
+            }
+        }
+    }
+
+    /// Stops drawing a spinner that couldn't be written, handing the error over to its handle
+    ///
+    /// A broken pipe means the reader has gone away and nothing else can be written either, so every spinner is stopped.
+    fn fail(&mut self, id: u64, e: io::Error) {
+        let broken_pipe = e.kind() == io::ErrorKind::BrokenPipe;
+
+        if let Some(entry) = self.spinners.remove(&id) {
+            // Try to bring the cursor back, there isn't much else to do if it fails again
+            let state = &entry.state;
+            let _ = state.output.with(|w| state.show_cursor(w, true));
+
+            entry.report(e);
+        }
+
+        if broken_pipe {
+            for (_, entry) in self.spinners.drain() {
+                entry.report(io::ErrorKind::BrokenPipe.into());
+            }
+
+            self.deadlines.clear();
+        }
+    }
+
+    /// The earliest time at which a spinner needs to be drawn
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.deadlines.peek().map(|&Reverse((due, _))| due)
+    }
+}
+
+/// The time by the spinner's own clock, when the scheduler is driven by real time
+fn local_time(state: &State, now: Instant, real_time: bool) -> Instant {
+    if real_time {
+        state.clock.now()
+    } else {
+        now
+    }
+}
+
+/// The render thread, drawing every registered spinner as its next frame comes due
+///
+/// Between frames the thread blocks on the channel, so events are applied as soon as they are sent rather than on the next frame.
+fn run(recv: Receiver<Message>) {
+    let mut scheduler = Scheduler::real_time();
+
+    loop {
+        if scheduler.spinners.is_empty() {
+            let mut global = SCHEDULER.lock().unwrap_or_else(|e| e.into_inner());
+
+            // A spinner may have been registered since the queue was last checked
+            match recv.try_recv() {
+                Ok(message) => {
+                    drop(global);
+                    scheduler.handle(message, Instant::now());
+                }
+                Err(_) => {
+                    *global = None;
+                    return;
+                }
+            }
+        } else if let Some(due) = scheduler.next_deadline() {
+            match recv.recv_timeout(due.saturating_duration_since(Instant::now())) {
+                Ok(message) => scheduler.handle(message, Instant::now()),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        } else {
+            // Every spinner is paused, so there is nothing to do until one is resumed
+            match recv.recv() {
+                Ok(message) => scheduler.handle(message, Instant::now()),
+                Err(_) => return,
+            }
+        }
+
+        scheduler.draw(Instant::now());
+    }
+}
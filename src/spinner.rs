@@ -1,16 +1,29 @@
 use std::{
-    io::{stdout, Write},
-    sync::mpsc::{channel, SendError, Sender, TryRecvError},
-    thread,
-    time::Duration,
+    borrow::Cow,
+    io::{self, Write},
+    sync::{mpsc::SendError, Arc, Mutex, MutexGuard},
+    time::Instant,
 };
 
 /// A type alias for the spinner frames type
 pub type Frames = &'static [&'static str];
 
+/// The symbol used by [`Spinner::stop_with_success`], unless set otherwise with [`Spinner::set_success_symbol`]
+pub const SUCCESS_SYMBOL: char = '✔';
+
+/// The symbol used by [`Spinner::stop_with_failure`], unless set otherwise with [`Spinner::set_failure_symbol`]
+pub const FAILURE_SYMBOL: char = '✖';
+
 use strum::Display;
 
-use crate::Spinners;
+use crate::{
+    env::Overrides,
+    mode::Mode,
+    scheduler::{self, Registration},
+    state::State,
+    Animation, AsciiFallback, Clock, Color, CustomSpinner, Error, Placement, SpinnerBuilder,
+    SpinnerHandle, SpinnerStyle, Spinners,
+};
 
 #[derive(Debug, Clone, Display)]
 /// All the different events that can occur
@@ -19,34 +32,185 @@ pub enum Event {
     Stop,
     /// Update the spinner message
     SetMessage(String),
+    /// Update the text shown before the spinner frame
+    SetPrefix(String),
+    /// Update the text shown after the spinner message
+    SetSuffix(String),
+    /// Update where the frame is placed relative to the message
+    SetPlacement(Placement),
     /// Update the spinner interval
     SetInterval(u64),
-    /// Update the spinner frames
+    /// Update the spinner frames to those of a built in spinner
     SetFrames(Frames),
+    /// Update the spinner frames to those of a [`CustomSpinner`]
+    SetCustomFrames(Vec<Cow<'static, str>>),
+    /// Update the order the frames are shown in
+    SetAnimation(Animation),
+    /// Stop animating and clear the spinner line
+    Pause,
+    /// Carry on animating after being paused
+    Resume,
+}
+
+/// What happens when a running [`Spinner`] is dropped without being stopped
+///
+/// Set with [`Spinner::set_drop_behavior`]. This makes sure the spinner line makes sense when a function returns early, for example through `?`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum DropBehavior {
+    /// Leave the last frame and message on screen as they are
+    #[default]
+    Persist,
+    /// Clear the spinner line
+    Clear,
+    /// Mark the spinner as failed, as with [`Spinner::stop_with_failure`]
+    Fail,
+    /// Mark the spinner as failed if it is dropped while the thread is panicking, otherwise leave it as it is
+    FailOnPanic,
+}
+
+/// The parts of a spinner shared between a [`Spinner`] and its [`SpinnerHandle`]s
+#[derive(Debug)]
+pub(crate) struct Shared {
+    link: Link,
+    state: State,
+    /// Whether the interval was set, rather than taken from the spinner style
+    interval_set: bool,
 }
 
-#[derive(Debug, Clone)]
+/// How a spinner is connected to the render thread
+#[derive(Debug, Default)]
+enum Link {
+    /// The spinner hasn't been started, or has been stopped
+    #[default]
+    Stopped,
+    /// The spinner is being drawn
+    Running(Registration),
+    /// The spinner was started with the animation turned off through the environment, so nothing is drawn until it is stopped
+    Hidden,
+    /// The spinner stopped being drawn because of an error, and can't be updated until it is started again
+    Lost,
+}
+
+impl Shared {
+    /// Sends an event to the render thread, if the spinner is running
+    ///
+    /// If an error is returned the spinner can no longer be drawn, so every later event fails with [`Error::Disconnected`] until it is started again.
+    fn send(&mut self, event: Event) -> Result<(), Error> {
+        let result = match &self.link {
+            Link::Stopped | Link::Hidden => Ok(()),
+            Link::Running(sender) => sender.send(event),
+            Link::Lost => Err(Error::Disconnected),
+        };
+
+        if result.is_err() {
+            self.link = Link::Lost;
+        }
+
+        result
+    }
+
+    pub fn set_message(&mut self, message: String) -> Result<(), Error> {
+        self.state.message = message;
+        self.send(Event::SetMessage(self.state.message.clone()))
+    }
+
+    pub fn set_prefix(&mut self, prefix: String) -> Result<(), Error> {
+        self.state.prefix = prefix;
+        self.send(Event::SetPrefix(self.state.prefix.clone()))
+    }
+
+    pub fn set_suffix(&mut self, suffix: String) -> Result<(), Error> {
+        self.state.suffix = suffix;
+        self.send(Event::SetSuffix(self.state.suffix.clone()))
+    }
+
+    pub fn set_interval(&mut self, interval: u64) -> Result<(), Error> {
+        if interval == 0 {
+            return Err(Error::Config(
+                "the interval must be at least 1ms".to_string(),
+            ));
+        }
+
+        let now = self.state.clock.now();
+        self.interval_set = true;
+        self.state.set_interval(interval, now);
+        self.send(Event::SetInterval(interval))
+    }
+
+    pub fn message(&self) -> String {
+        self.state.message.clone()
+    }
+
+    pub fn is_running(&self) -> bool {
+        matches!(self.link, Link::Running(_) | Link::Hidden)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.state.paused.is_some()
+    }
+}
+
+/// Locks the shared spinner state, carrying on even if another thread panicked while holding it
+pub(crate) fn lock(shared: &Mutex<Shared>) -> MutexGuard<'_, Shared> {
+    shared.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[derive(Debug)]
 /// Main spinner struct
 ///
 /// This holds all the information for the actual spinners
+///
+/// A `Spinner` owns the spinner it draws: dropping it stops the spinner, leaving the last frame on screen unless configured otherwise with [`Spinner::set_drop_behavior`].
+/// To update the spinner from other threads, use [`Spinner::handle`] to get a [`SpinnerHandle`], which can be cloned and dropped freely without affecting the spinner.
+///
+/// Unlike in 2.3.0, `Spinner` doesn't implement `Clone`, as dropping any clone stopped the spinner for all of them.
+/// Code that cloned a spinner to update it from elsewhere should clone a [`SpinnerHandle`] instead.
 pub struct Spinner {
-    /// The enum variant used in this spinner
-    pub spinner: Spinners,
-    sender: Option<Sender<Event>>,
-    frames: Frames,
-    interval: u64,
-    message: String,
+    /// The style used in this spinner, either one of the built in [`Spinners`] or a [`CustomSpinner`]
+    pub spinner: SpinnerStyle,
+    shared: Arc<Mutex<Shared>>,
+    drop_behavior: DropBehavior,
+    fallback: AsciiFallback,
+    ascii_fallback: bool,
+    env_overrides: bool,
+    overrides: Overrides,
+    mode: Mode,
+    color: Option<Color>,
+    success_symbol: String,
+    failure_symbol: String,
 }
 
 impl Drop for Spinner {
     fn drop(&mut self) {
-        self.stop();
+        if !self.is_running() {
+            return;
+        }
+
+        match self.drop_behavior {
+            DropBehavior::Persist => {
+                self.stop();
+            }
+            DropBehavior::Clear => self.stop_and_clear(),
+            DropBehavior::Fail => self.stop_with_failure(),
+            DropBehavior::FailOnPanic if std::thread::panicking() => self.stop_with_failure(),
+            DropBehavior::FailOnPanic => {
+                self.stop();
+            }
+        }
     }
 }
 
 impl Spinner {
     /// Create a new spinner along with a message
     ///
+    /// If the terminal doesn't seem to support Unicode when the spinner is started, spinners that aren't ASCII are swapped for [`Spinners::Line`].
+    /// See [`AsciiFallback`], and [`Spinner::set_ascii_fallback`] to turn this off.
+    ///
     /// # Examples
     ///
     /// ## Basic Usage:
@@ -66,53 +230,526 @@ impl Spinner {
     /// let mut sp: Spinner = Spinners::Dots.into();
     /// sp.start();
     /// ```
-    pub fn new(spinner: Spinners, message: impl std::fmt::Display) -> Self {
-        let frames = spinner.get_frames();
+    pub fn new(spinner: impl Into<SpinnerStyle>, message: impl std::fmt::Display) -> Self {
+        let spinner = spinner.into();
+        let state = State::new(spinner.frames(), spinner.interval(), message.to_string());
 
+        Self::from_state(spinner, state, AsciiFallback::default())
+    }
+
+    pub(crate) fn from_state(spinner: SpinnerStyle, state: State, fallback: AsciiFallback) -> Self {
         Self {
             spinner,
-            frames,
-            interval: 1000 / frames.len() as u64,
-            message: message.to_string(),
-            sender: None,
+            shared: Arc::new(Mutex::new(Shared {
+                state,
+                link: Link::Stopped,
+                interval_set: false,
+            })),
+            drop_behavior: DropBehavior::default(),
+            fallback,
+            ascii_fallback: true,
+            env_overrides: true,
+            overrides: Overrides::default(),
+            mode: Mode::default(),
+            color: None,
+            success_symbol: SUCCESS_SYMBOL.to_string(),
+            failure_symbol: FAILURE_SYMBOL.to_string(),
+        }
+    }
+
+    /// Creates a builder for configuring a spinner beyond its frames and message
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Output, Spinner, Spinners};
+    ///
+    /// let mut sp = Spinner::builder()
+    ///     .spinner(Spinners::Dots)
+    ///     .message("Doing some cool things...")
+    ///     .output(Output::Stderr)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// sp.start();
+    /// ```
+    pub fn builder() -> SpinnerBuilder {
+        SpinnerBuilder::new()
+    }
+
+    fn shared(&self) -> MutexGuard<'_, Shared> {
+        lock(&self.shared)
+    }
+
+    /// Sends an event to the render thread and waits for it to be handled, if the spinner is running
+    ///
+    /// The shared state isn't locked while waiting, so handles can carry on updating the spinner in the meantime.
+    fn send_and_wait(&self, event: Event) -> Result<(), Error> {
+        let sender = match &self.shared().link {
+            Link::Stopped | Link::Hidden => return Ok(()),
+            Link::Running(sender) => sender.clone(),
+            Link::Lost => return Err(Error::Disconnected),
+        };
+
+        let result = sender.send_and_wait(event);
+
+        if result.is_err() {
+            self.shared().link = Link::Lost;
+        }
+
+        result
+    }
+
+    /// The spinner actually drawn, after the environment and the terminal have had their say
+    fn shown_style(&self) -> SpinnerStyle {
+        let style = match self.overrides.style {
+            Some(spinner) => spinner.into(),
+            None => self.spinner.clone(),
+        };
+
+        if self.ascii_fallback {
+            self.fallback.resolve(style)
+        } else {
+            style
         }
     }
 
+    /// Gets a handle to the spinner, for updating it from other threads
+    ///
+    /// Handles can be cloned and sent to other threads, and dropping them has no effect on the spinner.
+    /// Once the `Spinner` itself is dropped, the spinner is stopped and updates made through its handles are no longer drawn.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinners, Spinner};
+    /// use std::{thread, time::Duration};
+    ///
+    /// let mut sp = Spinner::new(Spinners::Dots, "Starting...");
+    /// sp.start();
+    ///
+    /// let handle = sp.handle();
+    /// let worker = thread::spawn(move || {
+    ///     for step in 1..=3 {
+    ///         handle.set_message(format!("Step {}/3", step));
+    ///         thread::sleep(Duration::from_millis(100));
+    ///     }
+    /// });
+    ///
+    /// // The worker's handle is dropped once it's done, but the spinner keeps going
+    /// worker.join().unwrap();
+    /// assert!(sp.is_running());
+    /// assert_eq!(sp.get_message(), "Step 3/3");
+    ///
+    /// // Dropping the spinner stops it, even if there are handles left
+    /// let handle = sp.handle();
+    /// drop(sp);
+    /// assert!(!handle.is_running());
+    /// ```
+    pub fn handle(&self) -> SpinnerHandle {
+        SpinnerHandle::new(self.shared.clone())
+    }
+
     /// Start the spinner
     ///
     /// Explained more in depth in the [`Spinner::new`] function.
+    ///
+    /// All running spinners are drawn by a single background thread, which is started with the first spinner and exits once the last one is stopped.
+    ///
+    /// The environment variables described in [`Spinner::set_env_overrides`] are read here, unless turned off.
+    ///
+    /// Starting a spinner that is already running starts it over, from the first frame.
+    /// Spinners that can't be drawn, such as a [`CustomSpinner`] with an interval of zero, aren't started, see [`Spinner::try_start`].
     pub fn start(&mut self) {
-        let spinner = self.clone();
-
-        let (sender, recv) = channel::<Event>();
+        let _ = self.try_start();
+    }
 
-        thread::spawn(move || 'outer: loop {
-            let mut stdout = stdout();
-            let frames = spinner.frames;
+    /// Start the spinner, returning an error if it can't be drawn
+    ///
+    /// See [`Spinner::start`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if the spinner has an interval of zero or no frames, which can happen with a [`CustomSpinner`], or if it was set to [`Mode::Heartbeat`] with a heartbeat of zero.
+    /// The spinner is left as it was.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{CustomSpinner, Error, Spinner};
+    ///
+    /// let mut sp = Spinner::new(CustomSpinner::new("pulse", ["·", "•"], 0), "Thinking...");
+    ///
+    /// assert!(matches!(sp.try_start(), Err(Error::Config(_))));
+    /// assert!(!sp.is_running());
+    /// ```
+    pub fn try_start(&mut self) -> Result<(), Error> {
+        if self.env_overrides {
+            self.overrides = Overrides::from_env();
+        }
 
-            for frame in frames.iter() {
-                let mut message = spinner.message.clone();
-                let mut interval = spinner.interval;
+        let shown = self.shown_style();
+        let frames = shown.frames();
 
-                match recv.try_recv() {
-                    Ok(Event::Stop) | Err(TryRecvError::Disconnected) => break 'outer,
-                    Ok(Event::SetMessage(message_)) => message = message_,
-                    Ok(Event::SetInterval(interval_)) => interval = interval_,
-                    Ok(Event::SetFrames(_)) => break,
-                    Err(TryRecvError::Empty) => {}
-                };
+        // A spinner swapped in for the terminal or the environment runs at its own pace, unless an interval was set
+        let interval = {
+            let shared = self.shared();
 
-                print!("\r{} {}", frame, message);
-                stdout.flush().unwrap();
-                thread::sleep(Duration::from_millis(interval));
+            if shared.interval_set {
+                shared.state.interval
+            } else {
+                shown.interval()
             }
-        });
+        };
+
+        if frames.is_empty() {
+            return Err(Error::Config(format!(
+                "the spinner `{}` has no frames",
+                self.spinner
+            )));
+        }
+
+        if interval == 0 {
+            return Err(Error::Config(
+                "the interval must be at least 1ms".to_string(),
+            ));
+        }
+
+        if self.mode == Mode::Heartbeat(0) {
+            return Err(Error::Config(
+                "the heartbeat must be at least 1ms".to_string(),
+            ));
+        }
+
+        // The render thread would otherwise keep drawing the spinner as it was first started
+        let _ = self.try_stop();
+
+        let mut shared = self.shared();
+
+        shared.state.frames = frames;
+        shared.state.interval = interval;
+        shared.state.interval_multiplier = self.overrides.interval_multiplier.unwrap_or(1.0);
+        shared.state.mode = if self.overrides.accessible {
+            Mode::Accessible
+        } else {
+            self.mode.resolve()
+        };
+        shared.state.color = self.color.filter(|_| !self.overrides.no_color);
+        let now = shared.state.clock.now();
+        shared.state.started = now;
+        shared.state.paused = None;
+        shared.state.reset(now);
+
+        shared.link = if self.overrides.disabled {
+            Link::Hidden
+        } else {
+            Link::Running(scheduler::register(shared.state.clone()))
+        };
+
+        Ok(())
+    }
+
+    /// Sets where the spinner gets the current time from, defaults to the [`SystemClock`](crate::SystemClock)
+    ///
+    /// Tests can use a [`ManualClock`](crate::ManualClock) to move time along themselves, and check exactly what was drawn without sleeping.
+    /// Set the clock before starting the spinner, as a running spinner keeps the one it was started with.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Clock, ManualClock, Spinner, Spinners};
+    /// use std::time::Duration;
+    ///
+    /// let clock = ManualClock::new();
+    ///
+    /// let mut sp = Spinner::new(Spinners::Line, "Waiting...");
+    /// sp.set_interval(100);
+    /// sp.set_clock(clock.clone());
+    ///
+    /// assert_eq!(sp.current_frame(clock.now()), "-");
+    ///
+    /// clock.advance(Duration::from_millis(100));
+    /// assert_eq!(sp.current_frame(clock.now()), "\\");
+    /// ```
+    pub fn set_clock(&mut self, clock: impl Clock) {
+        let mut shared = self.shared();
+        let now = clock.now();
+
+        shared.state.clock = Arc::new(clock);
+        shared.state.started = now;
+        shared.state.reset(now);
+    }
+
+    /// Sets how the spinner is shown while running, defaults to [`Mode::Auto`]
+    ///
+    /// By default the spinner is animated, except on CI where it prints a heartbeat line every so often instead, see [`Mode`].
+    /// This takes effect the next time the spinner is started.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{is_ci, Mode, Spinner, Spinners};
+    ///
+    /// let mut sp = Spinner::new(Spinners::Dots, "Running the test suite");
+    ///
+    /// // Some CI systems give up after ten minutes without output, so check in more often than the default
+    /// if is_ci() {
+    ///     sp.set_mode(Mode::Heartbeat(5 * 60 * 1000));
+    /// }
+    ///
+    /// sp.start();
+    /// sp.stop_with_success();
+    /// ```
+    pub fn set_mode(&mut self, mode: Mode) {
+        self.mode = mode;
+    }
+
+    /// Sets whether spinners that aren't ASCII are swapped out when the terminal doesn't seem to support Unicode, defaults to `true`
+    ///
+    /// This is checked every time the spinner is started, see [`AsciiFallback`] for what they are swapped for.
+    /// The spinner swapped in runs at its own interval, unless one was set with [`Spinner::set_interval`] or [`SpinnerBuilder::interval`].
+    /// Turn it off for a spinner that should look the same everywhere, such as one whose output is checked in tests.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinner, Spinners};
+    /// use std::time::Instant;
+    ///
+    /// let mut sp = Spinner::new(Spinners::Dots, "Doing some cool things...");
+    /// sp.set_ascii_fallback(false);
+    /// sp.set_env_overrides(false);
+    ///
+    /// sp.start();
+    /// assert_eq!(sp.current_frame(Instant::now()), "⠋");
+    /// sp.stop();
+    /// ```
+    pub fn set_ascii_fallback(&mut self, enabled: bool) {
+        self.ascii_fallback = enabled;
+    }
+
+    /// Sets the color of the frame while the spinner is running, defaults to no color
+    ///
+    /// This takes effect the next time the spinner is started. See [`Color`].
+    pub fn set_color(&mut self, color: Option<Color>) {
+        self.color = color;
+    }
+
+    /// Sets the symbol shown by [`Spinner::stop_with_success`], defaults to [`SUCCESS_SYMBOL`]
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinner, Spinners};
+    ///
+    /// let mut sp = Spinner::new(Spinners::Line, "Doing some cool things...");
+    /// sp.set_success_symbol("OK");
+    /// sp.set_failure_symbol("ERR");
+    ///
+    /// sp.start();
+    /// sp.stop_with_success();
+    /// ```
+    pub fn set_success_symbol<S: std::fmt::Display>(&mut self, symbol: S) {
+        self.success_symbol = symbol.to_string();
+    }
+
+    /// Sets the symbol shown by [`Spinner::stop_with_failure`], defaults to [`FAILURE_SYMBOL`]
+    ///
+    /// See [`Spinner::set_success_symbol`].
+    pub fn set_failure_symbol<S: std::fmt::Display>(&mut self, symbol: S) {
+        self.failure_symbol = symbol.to_string();
+    }
+
+    /// Sets whether the spinner can be changed through environment variables, defaults to `true`
+    ///
+    /// This lets users change how spinners look without changing the program. They are read every time the spinner is started:
+    ///
+    /// - [`ENV_ENABLED`](crate::ENV_ENABLED) (`SPINNERS_RS`): set to `off`, `0` or `false` to turn off the animation. Only the final line written when the spinner is stopped is shown.
+    /// - [`ENV_STYLE`](crate::ENV_STYLE) (`SPINNERS_RS_STYLE`): the name of a spinner to show instead, such as `line`.
+    /// - [`ENV_ACCESSIBLE`](crate::ENV_ACCESSIBLE) (`SPINNERS_RS_ACCESSIBLE`): set to `1`, `true` or `on` to show the spinner in [`Mode::Accessible`].
+    /// - [`ENV_INTERVAL_MULTIPLIER`](crate::ENV_INTERVAL_MULTIPLIER) (`SPINNERS_RS_INTERVAL_MULTIPLIER`): multiplies the time between frames, such as `2` for half the speed.
+    /// - `NO_COLOR`: set to anything to leave out the [`Color`] of the frame.
+    ///
+    /// Programs that already let users configure their spinners can turn this off, so the two don't fight.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinner, Spinners};
+    /// use std::time::Instant;
+    ///
+    /// // The program has a `--spinner` flag of its own, so `SPINNERS_RS_STYLE` shouldn't change it
+    /// let mut sp = Spinner::new(Spinners::Line, "Loading...");
+    /// sp.set_env_overrides(false);
+    ///
+    /// sp.start();
+    /// assert!(sp.is_running());
+    /// assert_eq!(sp.current_frame(Instant::now()), "-");
+    /// sp.stop();
+    /// ```
+    pub fn set_env_overrides(&mut self, enabled: bool) {
+        self.env_overrides = enabled;
+
+        if !enabled {
+            self.overrides = Overrides::default();
+        }
+    }
+
+    /// Draws the current frame to the spinner output, without a background thread
+    ///
+    /// This is meant for programs that already have a frame loop of their own, such as TUIs and games.
+    /// Instead of calling [`Spinner::start`], call this on every iteration of the loop.
+    /// The frame shown is based on the time elapsed since the spinner was created (or last started), so it does not matter how often it is called.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinners, Spinner};
+    /// use std::{thread, time::Duration};
+    ///
+    /// let mut sp = Spinner::new(Spinners::Dots, "Doing some cool things...");
+    ///
+    /// for _ in 0..60 {
+    ///     sp.tick().unwrap();
+    ///
+    ///     thread::sleep(Duration::from_millis(16));
+    /// }
+    /// ```
+    pub fn tick(&mut self) -> io::Result<()> {
+        let output = self.shared().state.output.clone();
+
+        output.with(|mut w| self.render_to(&mut w))
+    }
+
+    /// Draws the current frame to the given writer, without a background thread
+    ///
+    /// Works the same way as [`Spinner::tick`], for when the spinner should be written somewhere else.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinners, Spinner};
+    ///
+    /// let mut sp = Spinner::new(Spinners::Line, "Doing some cool things...");
+    /// let mut out = Vec::new();
+    ///
+    /// sp.render_to(&mut out).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(out).unwrap(), "\r- Doing some cool things...\x1b[K");
+    /// ```
+    pub fn render_to(&mut self, w: &mut impl Write) -> io::Result<()> {
+        let shared = self.shared();
+
+        if shared.is_paused() {
+            return Ok(());
+        }
+
+        shared.state.render(w, shared.state.clock.now())
+    }
+
+    /// Gets the frame that is shown at the given point in time
+    ///
+    /// This does not draw anything, making it useful for embedding the spinner in other output.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinners, Spinner};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let mut sp: Spinner = Spinners::Line.into();
+    /// sp.set_interval(100);
+    ///
+    /// let now = Instant::now();
+    /// let later = now + Duration::from_millis(100);
+    ///
+    /// assert_ne!(sp.current_frame(now), sp.current_frame(later));
+    /// ```
+    pub fn current_frame(&self, at: Instant) -> String {
+        self.shared().state.frame(at).to_string()
+    }
+
+    /// Pauses the spinner and clears its line
+    ///
+    /// This only returns once the line has been cleared, so anything printed afterwards (such as a prompt) appears cleanly.
+    /// Use [`Spinner::resume`] to bring the spinner back, with the same message and carrying on from the same frame.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinners, Spinner};
+    /// use std::{thread, time::Duration};
+    ///
+    /// let mut sp = Spinner::new(Spinners::Dots, "Doing some cool things...");
+    /// sp.start();
+    ///
+    /// thread::sleep(Duration::from_millis(1000));
+    ///
+    /// sp.pause();
+    /// println!("Are you sure? [y/N] y");
+    /// sp.resume();
+    ///
+    /// thread::sleep(Duration::from_millis(1000));
+    ///
+    /// sp.stop();
+    /// ```
+    pub fn pause(&mut self) {
+        let _ = self.try_pause();
+    }
+
+    /// Pauses the spinner and clears its line, returning any error that occurred
+    ///
+    /// See [`Spinner::pause`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the render thread is no longer running, or if the spinner couldn't be written to the terminal.
+    pub fn try_pause(&mut self) -> Result<(), Error> {
+        let mut shared = self.shared();
+
+        if shared.is_paused() {
+            return Ok(());
+        }
+
+        let now = shared.state.clock.now();
+        shared.state.pause(now);
+        if let Link::Stopped = shared.link {
+            let state = &shared.state;
+            state.output.with(|w| state.clear(w))?;
+            return Ok(());
+        }
 
-        self.sender = Some(sender);
+        drop(shared);
+        self.send_and_wait(Event::Pause)
+    }
+
+    /// Resumes the spinner after it was paused with [`Spinner::pause`]
+    pub fn resume(&mut self) {
+        let _ = self.try_resume();
+    }
+
+    /// Resumes the spinner after it was paused, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the render thread is no longer running, or if the spinner couldn't be written to the terminal.
+    pub fn try_resume(&mut self) -> Result<(), Error> {
+        let mut shared = self.shared();
+
+        let now = shared.state.clock.now();
+        shared.state.resume(now);
+        shared.send(Event::Resume)
+    }
+
+    /// Whether the spinner is currently paused
+    pub fn is_paused(&self) -> bool {
+        self.shared().is_paused()
     }
 
     /// Stops the spinner from running
     ///
+    /// This waits for the render thread to let go of the spinner, so nothing else will be drawn over the spinner line once it returns.
+    ///
     /// Alternatively you can use the [`Spinner::stop_with_message`] or [`Spinner::stop_with_symbol`] function.
     ///
     /// # Example:
@@ -129,14 +766,104 @@ impl Spinner {
     /// sp.stop();
     /// ```
     pub fn stop(&mut self) -> Option<SendError<Event>> {
-        let mut e = None;
-        if let Some(sender) = &self.sender {
-            e = sender.send(Event::Stop).err();
+        match self.try_stop() {
+            Err(Error::Disconnected) => Some(SendError(Event::Stop)),
+            _ => None,
         }
+    }
+
+    /// Stops the spinner from running, returning any error that occurred
+    ///
+    /// The spinner is stopped even if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the render thread is no longer running, or if the spinner couldn't be written to the terminal before it was stopped.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinners, Spinner};
+    /// use std::{thread, time::Duration};
+    ///
+    /// let mut sp: Spinner = Spinners::Dots.into();
+    /// sp.start();
+    ///
+    /// thread::sleep(Duration::from_millis(1000));
+    ///
+    /// if let Err(e) = sp.try_stop() {
+    ///     eprintln!("The spinner failed: {}", e);
+    /// }
+    /// ```
+    pub fn try_stop(&mut self) -> Result<(), Error> {
+        let result = self.send_and_wait(Event::Stop);
+
+        if result.is_ok() {
+            self.shared().link = Link::Stopped;
+        }
+
+        result
+    }
+
+    /// Stops the spinner and clears its line
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinners, Spinner};
+    /// use std::{thread, time::Duration};
+    ///
+    /// let mut sp: Spinner = Spinners::Dots.into();
+    /// sp.start();
+    ///
+    /// thread::sleep(Duration::from_millis(1000));
+    ///
+    /// sp.stop_and_clear();
+    /// ```
+    pub fn stop_and_clear(&mut self) {
+        let _ = self.try_stop_and_clear();
+    }
+
+    /// Stops the spinner and clears its line, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the render thread is no longer running, or if the line couldn't be cleared.
+    pub fn try_stop_and_clear(&mut self) -> Result<(), Error> {
+        let stopped = self.try_stop();
 
-        self.sender = None;
+        let shared = self.shared();
+        let state = &shared.state;
+        state.output.with(|w| state.clear(w))?;
 
-        e
+        stopped
+    }
+
+    /// Sets what happens when the spinner is dropped while it is still running
+    ///
+    /// By default the last frame is left on screen, see [`DropBehavior`] for the alternatives.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{DropBehavior, Spinners, Spinner};
+    ///
+    /// fn compile() -> Result<(), String> {
+    ///     let mut sp = Spinner::new(Spinners::Dots, "Compiling...");
+    ///     sp.set_drop_behavior(DropBehavior::Fail);
+    ///     sp.start();
+    ///
+    ///     // Returning early shows the spinner as failed
+    ///     Err("Syntax error".to_string())?;
+    ///
+    ///     sp.stop_with_success();
+    ///     Ok(())
+    /// }
+    ///
+    /// assert!(compile().is_err());
+    /// ```
+    pub fn set_drop_behavior(&mut self, drop_behavior: DropBehavior) {
+        self.drop_behavior = drop_behavior;
     }
 
     /// Stops the spinner and replaces it with the given message
@@ -155,13 +882,31 @@ impl Spinner {
     /// sp.stop_with_message("We've finished that thing!");
     /// ```
     pub fn stop_with_message<S: std::fmt::Display>(&mut self, message: S) {
-        self.stop();
-        print!("\r{}", message);
-        stdout().flush().unwrap();
+        let _ = self.try_stop_with_message(message);
+    }
+
+    /// Stops the spinner and replaces it with the given message, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the render thread is no longer running, or if the spinner or message couldn't be written to the terminal.
+    pub fn try_stop_with_message<S: std::fmt::Display>(&mut self, message: S) -> Result<(), Error> {
+        let stopped = self.try_stop();
+
+        let shared = self.shared();
+        let state = &shared.state;
+        state
+            .output
+            .with(|w| state.finish(w, &message.to_string()))?;
+
+        stopped
     }
 
     /// Stops the spinner and replaces the current frame with the given symbol
     ///
+    /// The symbol is placed the same way as the frame (see [`Spinner::set_placement`]).
+    /// The prefix is kept, but the suffix is left out as it is only meant to be shown while the spinner is running.
+    ///
     /// # Example:
     ///
     /// ```
@@ -176,9 +921,83 @@ impl Spinner {
     /// sp.stop_with_symbol('✓');
     /// ```
     pub fn stop_with_symbol<S: std::fmt::Display>(&mut self, symbol: S) {
-        self.stop();
-        print!("\r{} {}", symbol, self.message);
-        stdout().flush().unwrap();
+        let _ = self.try_stop_with_symbol(symbol);
+    }
+
+    /// Stops the spinner and replaces the current frame with the given symbol, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the render thread is no longer running, or if the spinner or symbol couldn't be written to the terminal.
+    pub fn try_stop_with_symbol<S: std::fmt::Display>(&mut self, symbol: S) -> Result<(), Error> {
+        let message = self.shared().state.line(&symbol.to_string(), false);
+        self.try_stop_with_message(message)
+    }
+
+    /// Stops the spinner and marks it as successful, replacing the current frame with the success symbol
+    ///
+    /// The symbol is [`SUCCESS_SYMBOL`] unless set otherwise with [`Spinner::set_success_symbol`].
+    ///
+    /// In [`Mode::Accessible`] the line ends with `done` instead.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinners, Spinner};
+    /// use std::{thread, time::Duration};
+    ///
+    /// let mut sp = Spinner::new(Spinners::Dots, "Doing some cool things...");
+    /// sp.start();
+    ///
+    /// thread::sleep(Duration::from_millis(1000));
+    ///
+    /// sp.stop_with_success();
+    /// ```
+    pub fn stop_with_success(&mut self) {
+        let _ = self.try_stop_with_success();
+    }
+
+    /// Stops the spinner and marks it as successful, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// See [`Spinner::try_stop_with_symbol`].
+    pub fn try_stop_with_success(&mut self) -> Result<(), Error> {
+        let message = self.shared().state.outcome(&self.success_symbol, "done");
+        self.try_stop_with_message(message)
+    }
+
+    /// Stops the spinner and marks it as failed, replacing the current frame with the failure symbol
+    ///
+    /// The symbol is [`FAILURE_SYMBOL`] unless set otherwise with [`Spinner::set_failure_symbol`].
+    ///
+    /// In [`Mode::Accessible`] the line ends with `failed` instead.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinners, Spinner};
+    /// use std::{thread, time::Duration};
+    ///
+    /// let mut sp = Spinner::new(Spinners::Dots, "Doing some cool things...");
+    /// sp.start();
+    ///
+    /// thread::sleep(Duration::from_millis(1000));
+    ///
+    /// sp.stop_with_failure();
+    /// ```
+    pub fn stop_with_failure(&mut self) {
+        let _ = self.try_stop_with_failure();
+    }
+
+    /// Stops the spinner and marks it as failed, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// See [`Spinner::try_stop_with_symbol`].
+    pub fn try_stop_with_failure(&mut self) -> Result<(), Error> {
+        let message = self.shared().state.outcome(&self.failure_symbol, "failed");
+        self.try_stop_with_message(message)
     }
 
     /// Updates the frame interval
@@ -207,11 +1026,17 @@ impl Spinner {
     /// sp.stop();
     /// ```
     pub fn set_interval(&mut self, interval: u64) {
-        if let Some(sender) = &self.sender {
-            sender.send(Event::SetInterval(interval)).unwrap();
-        } else {
-            self.interval = interval;
-        }
+        let _ = self.try_set_interval(interval);
+    }
+
+    /// Updates the frame interval, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if the interval is zero, leaving the current one in place.
+    /// Otherwise returns an error if the render thread is no longer running, or if the spinner couldn't be written to the terminal since the last update.
+    pub fn try_set_interval(&mut self, interval: u64) -> Result<(), Error> {
+        self.shared().set_interval(interval)
     }
 
     /// Sets the message to display
@@ -236,16 +1061,140 @@ impl Spinner {
     /// sp.stop();
     /// ```
     pub fn set_message<S: std::fmt::Display>(&mut self, message: S) {
-        if let Some(sender) = &self.sender {
-            sender.send(Event::SetMessage(message.to_string())).unwrap();
-        } else {
-            self.message = message.to_string();
-        }
+        let _ = self.try_set_message(message);
+    }
+
+    /// Sets the message to display, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the render thread is no longer running, or if the spinner couldn't be written to the terminal since the last update.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinners, Spinner};
+    ///
+    /// let mut sp: Spinner = Spinners::Dots.into();
+    /// sp.start();
+    ///
+    /// sp.try_set_message("Doing some cool things...").unwrap();
+    /// ```
+    pub fn try_set_message<S: std::fmt::Display>(&mut self, message: S) -> Result<(), Error> {
+        self.shared().set_message(message.to_string())
+    }
+
+    /// Sets the text shown before the spinner frame, such as `[api]` or `[3/8]`
+    ///
+    /// Like the message, this can be set before or after a spinner is started, and is kept when the spinner is stopped with a symbol.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinners, Spinner, TestTerminal};
+    ///
+    /// let mut sp = Spinner::new(Spinners::Line, "Compiling...");
+    /// sp.set_prefix("[1/8]");
+    /// sp.set_suffix("(press q to cancel)");
+    ///
+    /// let mut terminal = TestTerminal::new();
+    /// sp.render_to(&mut terminal).unwrap();
+    ///
+    /// assert_eq!(terminal.screen(), "[1/8] - Compiling... (press q to cancel)");
+    /// ```
+    pub fn set_prefix<S: std::fmt::Display>(&mut self, prefix: S) {
+        let _ = self.try_set_prefix(prefix);
+    }
+
+    /// Sets the text shown before the spinner frame, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the render thread is no longer running, or if the spinner couldn't be written to the terminal since the last update.
+    pub fn try_set_prefix<S: std::fmt::Display>(&mut self, prefix: S) -> Result<(), Error> {
+        self.shared().set_prefix(prefix.to_string())
+    }
+
+    /// Sets the text shown after the spinner message, such as a hint
+    ///
+    /// Unlike the prefix, the suffix is left out once the spinner is stopped with a symbol.
+    /// See [`Spinner::set_prefix`] for an example.
+    pub fn set_suffix<S: std::fmt::Display>(&mut self, suffix: S) {
+        let _ = self.try_set_suffix(suffix);
+    }
+
+    /// Sets the text shown after the spinner message, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the render thread is no longer running, or if the spinner couldn't be written to the terminal since the last update.
+    pub fn try_set_suffix<S: std::fmt::Display>(&mut self, suffix: S) -> Result<(), Error> {
+        self.shared().set_suffix(suffix.to_string())
+    }
+
+    /// Sets where the frame is placed relative to the message
+    ///
+    /// Defaults to [`Placement::left`], the frame followed by a space and the message.
+    /// See [`Placement`] for an example.
+    pub fn set_placement(&mut self, placement: Placement) {
+        let _ = self.try_set_placement(placement);
+    }
+
+    /// Sets where the frame is placed relative to the message, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the render thread is no longer running, or if the spinner couldn't be written to the terminal since the last update.
+    pub fn try_set_placement(&mut self, placement: Placement) -> Result<(), Error> {
+        let mut shared = self.shared();
+
+        shared.state.placement = placement.clone();
+        shared.send(Event::SetPlacement(placement))
+    }
+
+    /// Gets the message currently being displayed
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinners, Spinner};
+    ///
+    /// let mut sp = Spinner::new(Spinners::Dots, "Doing some cool things...");
+    /// assert_eq!(sp.get_message(), "Doing some cool things...");
+    ///
+    /// sp.set_message("Doing other things...");
+    /// assert_eq!(sp.get_message(), "Doing other things...");
+    /// ```
+    pub fn get_message(&self) -> String {
+        self.shared().message()
+    }
+
+    /// Whether the spinner has been started and not yet stopped
+    ///
+    /// This is also `true` for spinners started with the animation turned off through the environment, see [`Spinner::set_env_overrides`].
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Spinners, Spinner};
+    ///
+    /// let mut sp: Spinner = Spinners::Dots.into();
+    /// assert!(!sp.is_running());
+    ///
+    /// sp.start();
+    /// assert!(sp.is_running());
+    ///
+    /// sp.stop();
+    /// assert!(!sp.is_running());
+    /// ```
+    pub fn is_running(&self) -> bool {
+        self.shared().is_running()
     }
 
     /// Changes the spinner mid run
     ///
     /// This will change the spinner to the given one, allowing you to change the frames shown, on the current spinner without allocating a new variable and memory.
+    /// This accepts any of the built in [`Spinners`] as well as a [`CustomSpinner`].
     ///
     /// # Example:
     ///
@@ -258,7 +1207,7 @@ impl Spinner {
     ///
     /// let sps = Spinners::iter().collect::<Vec<Spinners>>();
     /// let len = sps.len();
-    /// let sp = sps.get(0).unwrap();
+    /// let sp = sps.first().unwrap();
     /// let mut spinner: Spinner = (*sp).into();
     /// spinner.start();
     ///
@@ -268,13 +1217,62 @@ impl Spinner {
     ///     thread::sleep(Duration::from_millis(1000));
     /// }
     /// ```
-    pub fn set_spinner(&mut self, spinner: Spinners) {
-        self.spinner = spinner;
-        if let Some(sender) = &self.sender {
-            sender.send(Event::SetFrames(spinner.get_frames())).unwrap();
-        } else {
-            self.frames = spinner.get_frames();
-        }
+    pub fn set_spinner(&mut self, spinner: impl Into<SpinnerStyle>) {
+        let _ = self.try_set_spinner(spinner);
+    }
+
+    /// Changes the spinner mid run, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the render thread is no longer running, or if the spinner couldn't be written to the terminal since the last update.
+    pub fn try_set_spinner(&mut self, spinner: impl Into<SpinnerStyle>) -> Result<(), Error> {
+        self.spinner = spinner.into();
+        let shown = self.shown_style();
+
+        let mut shared = self.shared();
+        let now = shared.state.clock.now();
+        shared.state.set_frames(shown.frames(), now);
+        shared.send(match shown {
+            SpinnerStyle::Builtin(spinner) => Event::SetFrames(spinner.get_frames()),
+            SpinnerStyle::Custom(_) => Event::SetCustomFrames(shown.frames()),
+        })
+    }
+
+    /// Sets the order the frames are shown in
+    ///
+    /// Defaults to [`Animation::Forward`], see [`Animation`] for the alternatives.
+    /// Changing the animation starts it again from the first frame.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{Animation, Spinner, Spinners};
+    /// use std::{thread, time::Duration};
+    ///
+    /// let mut sp = Spinner::new(Spinners::Clock, "Going back in time...");
+    /// sp.set_animation(Animation::Reverse);
+    /// sp.start();
+    ///
+    /// thread::sleep(Duration::from_millis(1000));
+    ///
+    /// sp.stop();
+    /// ```
+    pub fn set_animation(&mut self, animation: Animation) {
+        let _ = self.try_set_animation(animation);
+    }
+
+    /// Sets the order the frames are shown in, returning any error that occurred
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the render thread is no longer running, or if the spinner couldn't be written to the terminal since the last update.
+    pub fn try_set_animation(&mut self, animation: Animation) -> Result<(), Error> {
+        let mut shared = self.shared();
+
+        let now = shared.state.clock.now();
+        shared.state.set_animation(animation, now);
+        shared.send(Event::SetAnimation(animation))
     }
 
     /// Gets the spinner name capitalizes the first letter.
@@ -288,10 +1286,13 @@ impl Spinner {
     /// assert_eq!(sp.get_name(), "Dots");
     /// ```
     pub fn get_name(&self) -> String {
-        let sp_string = self.spinner.to_string().chars().collect::<Vec<char>>();
+        let name = self.spinner.to_string();
+        let mut chars = name.chars();
 
-        sp_string[0].to_uppercase().to_string()
-            + sp_string[1..].iter().cloned().collect::<String>().as_str()
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
     }
 }
 
@@ -300,3 +1301,9 @@ impl From<Spinners> for Spinner {
         Spinner::new(spinner, "")
     }
 }
+
+impl From<CustomSpinner> for Spinner {
+    fn from(spinner: CustomSpinner) -> Self {
+        Spinner::new(spinner, "")
+    }
+}
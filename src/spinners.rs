@@ -1,16 +1,28 @@
-use strum::{Display, EnumIter, EnumString};
+use strum::{Display, EnumIter};
 
-#[derive(Debug, Clone, Copy, EnumIter, Display, EnumString)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, Display)]
 #[strum(serialize_all = "camelCase")]
 /// The enum of all available spinners
 ///
-/// Implements [`Display`], [`EnumString`] and [`EnumIter`] traits for easy usage.
+/// Implements [`Display`], [`FromStr`](std::str::FromStr) and [`EnumIter`] traits for easy usage.
+/// Parsing is lenient about case and separators, so `dots8Bit`, `Dots8Bit`, `dots-8-bit` and `dots_8_bit` all work.
 ///
 /// Additionally implements [`Into<Spinner>`] trait to be easily converted into a [`Spinner`] struct.
 ///
 /// [`Spinner`]: ./struct.Spinner.html
 ///
-/// Can also be iterated over
+/// Can also be iterated over, either all at once or by [`Category`](crate::Category) with [`Spinners::in_category`]
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::Spinners;
+///
+/// assert_eq!("dots8Bit".parse::<Spinners>().unwrap(), Spinners::Dots8Bit);
+/// assert_eq!("Dots8Bit".parse::<Spinners>().unwrap(), Spinners::Dots8Bit);
+/// assert_eq!("dots-8-bit".parse::<Spinners>().unwrap(), Spinners::Dots8Bit);
+/// assert_eq!("DOTS_8_BIT".parse::<Spinners>().unwrap(), Spinners::Dots8Bit);
+/// ```
 pub enum Spinners {
     /// The Dots spinner
     Dots,
@@ -846,7 +858,7 @@ impl Spinners {
             ],
             Spinners::Line => &[
             r"-",
-            r"",
+            r"\",
             r"|",
             r"/"
             ],
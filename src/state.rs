@@ -0,0 +1,281 @@
+use std::{
+    borrow::Cow,
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    io::{self, Write},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    mode::{format_elapsed, Mode, ACCESSIBLE_INDICATOR},
+    template::Template,
+    Animation, Clock, Color, Output, Placement, SystemClock,
+};
+
+/// Everything needed to draw a spinner at a given point in time
+///
+/// The current frame is never stored, it is worked out from the time elapsed since `epoch`.
+/// This keeps both the render thread and manually ticked spinners in sync with the wall clock.
+#[derive(Debug, Clone)]
+pub(crate) struct State {
+    pub frames: Vec<Cow<'static, str>>,
+    pub interval: u64,
+    /// Stretches the interval, without changing the one that was asked for
+    pub interval_multiplier: f64,
+    pub message: String,
+    pub prefix: String,
+    pub suffix: String,
+    pub placement: Placement,
+    /// Lays out the line instead of `placement`, when set
+    pub template: Option<Template>,
+    /// The color of the frame while running, left out once the environment has been checked for `NO_COLOR`
+    pub color: Option<Color>,
+    pub animation: Animation,
+    /// Used to shuffle the frames for [`Animation::Random`]
+    pub seed: u64,
+    pub epoch: Instant,
+    pub paused: Option<Instant>,
+    pub output: Output,
+    pub hide_cursor: bool,
+    /// How the render thread shows the spinner, never [`Mode::Auto`] once started
+    pub mode: Mode,
+    /// When the spinner was last started, for the time shown on heartbeat lines
+    pub started: Instant,
+    /// Where the times passed to the other methods come from, they never read it themselves
+    pub clock: Arc<dyn Clock>,
+}
+
+impl State {
+    pub fn new(frames: Vec<Cow<'static, str>>, interval: u64, message: String) -> Self {
+        Self {
+            frames,
+            interval,
+            interval_multiplier: 1.0,
+            message,
+            prefix: String::new(),
+            suffix: String::new(),
+            placement: Placement::default(),
+            template: None,
+            color: None,
+            animation: Animation::default(),
+            seed: RandomState::new().build_hasher().finish(),
+            epoch: Instant::now(),
+            paused: None,
+            output: Output::default(),
+            hide_cursor: false,
+            mode: Mode::Animated,
+            started: Instant::now(),
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    fn interval(&self) -> Duration {
+        let interval = (self.interval as f64 * self.interval_multiplier).round() as u64;
+
+        Duration::from_millis(interval.max(1))
+    }
+
+    /// The amount of whole frames that have passed since the epoch
+    fn ticks(&self, at: Instant) -> u128 {
+        // Time stands still while paused
+        let at = self.paused.unwrap_or(at);
+
+        at.saturating_duration_since(self.epoch).as_millis() / self.interval().as_millis()
+    }
+
+    /// The index into `frames` of the frame shown at the given time
+    pub fn frame_index(&self, at: Instant) -> usize {
+        self.animation
+            .index(self.ticks(at), self.frames.len(), self.seed)
+    }
+
+    /// The frame shown at the given time
+    pub fn frame(&self, at: Instant) -> &str {
+        self.frames
+            .get(self.frame_index(at))
+            .map(|frame| frame.as_ref())
+            .unwrap_or_default()
+    }
+
+    /// The time at which the frame after the one shown at `at` is due
+    pub fn next_frame(&self, at: Instant) -> Instant {
+        let ticks = self.ticks(at) + 1;
+
+        self.epoch + Duration::from_millis((ticks * self.interval().as_millis()) as u64)
+    }
+
+    /// The amount of whole heartbeats that have passed since the spinner was started
+    fn heartbeats(&self, heartbeat: u64, at: Instant) -> u128 {
+        at.saturating_duration_since(self.started).as_millis() / heartbeat.max(1) as u128
+    }
+
+    /// The time at which the render thread next needs to draw the spinner, if it ever does without being changed
+    pub fn next_draw(&self, at: Instant) -> Option<Instant> {
+        match self.mode {
+            Mode::Heartbeat(heartbeat) => {
+                let beats = self.heartbeats(heartbeat, at) + 1;
+
+                Some(
+                    self.started + Duration::from_millis((beats * heartbeat.max(1) as u128) as u64),
+                )
+            }
+            Mode::Accessible => None,
+            Mode::Auto | Mode::Animated => Some(self.next_frame(at)),
+        }
+    }
+
+    /// Draws the spinner as the render thread shows it in its mode
+    ///
+    /// Heartbeats write whole lines: the message when the spinner starts, then a reminder that it is still running.
+    /// The accessible mode writes a whole line with a static indicator every time it is drawn.
+    pub fn draw(&self, w: &mut dyn Write, at: Instant) -> io::Result<()> {
+        let heartbeat = match self.mode {
+            Mode::Heartbeat(heartbeat) => heartbeat,
+            Mode::Accessible => {
+                writeln!(w, "{}", self.line(ACCESSIBLE_INDICATOR, true))?;
+                return w.flush();
+            }
+            Mode::Auto | Mode::Animated => return self.render(w, at),
+        };
+
+        if self.heartbeats(heartbeat, at) == 0 {
+            let line = self.line("", false);
+
+            if line.is_empty() {
+                return Ok(());
+            }
+
+            writeln!(w, "{}", line)?;
+        } else {
+            let elapsed = format_elapsed(at.saturating_duration_since(self.started));
+
+            match self.message.as_str() {
+                "" => writeln!(w, "still running ({})", elapsed)?,
+                message => writeln!(w, "still running: {} ({})", message, elapsed)?,
+            }
+        }
+
+        w.flush()
+    }
+
+    /// The final line for a spinner that succeeded or failed, with the outcome as a symbol or as a word when accessible
+    pub fn outcome(&self, symbol: &str, word: &str) -> String {
+        if self.mode != Mode::Accessible {
+            return self.line(symbol, false);
+        }
+
+        match self.line("", false) {
+            line if line.is_empty() => word.to_string(),
+            line => format!("{}: {}", line, word),
+        }
+    }
+
+    /// Writes the final line, in place of the spinner line when it is animated
+    pub fn finish(&self, w: &mut dyn Write, message: &str) -> io::Result<()> {
+        if self.mode.is_animated() {
+            write!(w, "\r{}\x1b[K", message)?;
+        } else {
+            writeln!(w, "{}", message)?;
+        }
+
+        w.flush()
+    }
+
+    /// Restarts the animation from the first frame
+    pub fn reset(&mut self, at: Instant) {
+        self.epoch = self.paused.unwrap_or(at);
+    }
+
+    /// Freezes the animation at the frame shown at the given time
+    pub fn pause(&mut self, at: Instant) {
+        if self.paused.is_none() {
+            self.paused = Some(at);
+        }
+    }
+
+    /// Carries on the animation from where it was paused
+    pub fn resume(&mut self, at: Instant) {
+        if let Some(paused) = self.paused.take() {
+            self.epoch += at.saturating_duration_since(paused);
+        }
+    }
+
+    /// Changes the interval, without jumping away from the frame currently shown
+    pub fn set_interval(&mut self, interval: u64, at: Instant) {
+        let at = self.paused.unwrap_or(at);
+        let period = self.animation.period(self.frames.len()) as u128;
+        let position = (self.ticks(at) % period) as u32;
+
+        self.interval = interval;
+        self.epoch = at.checked_sub(self.interval() * position).unwrap_or(at);
+    }
+
+    /// Changes the order the frames are shown in, starting again from the first one
+    pub fn set_animation(&mut self, animation: Animation, at: Instant) {
+        self.animation = animation;
+        self.reset(at);
+    }
+
+    /// Changes the frames, starting again from the first one
+    pub fn set_frames(&mut self, frames: Vec<Cow<'static, str>>, at: Instant) {
+        self.frames = frames;
+        self.reset(at);
+    }
+
+    /// Puts together the spinner line, with the given symbol in place of the frame
+    ///
+    /// The frame and message are placed according to `placement`.
+    /// Empty segments are left out, so there are no double spaces when there is no prefix or message.
+    /// The suffix is meant as a hint while the spinner is running, so it can be left out of the final line.
+    pub fn line(&self, symbol: &str, suffix: bool) -> String {
+        let suffix = if suffix { self.suffix.as_str() } else { "" };
+
+        if let Some(template) = &self.template {
+            return template.render(&self.prefix, symbol, &self.message, suffix);
+        }
+
+        let body = self.placement.join(symbol, &self.message);
+
+        [self.prefix.as_str(), body.as_str(), suffix]
+            .iter()
+            .filter(|segment| !segment.is_empty())
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Draws the spinner line as it should look at the given time
+    ///
+    /// Whatever is left of the previous line is erased, in case the new one is shorter.
+    pub fn render(&self, w: &mut dyn Write, at: Instant) -> io::Result<()> {
+        let frame = match self.color {
+            Some(color) => color.paint(self.frame(at)),
+            None => self.frame(at).to_string(),
+        };
+
+        write!(w, "\r{}\x1b[K", self.line(&frame, true))?;
+        w.flush()
+    }
+
+    /// Erases the spinner line, leaving the cursor at the start of it
+    pub fn clear(&self, w: &mut dyn Write) -> io::Result<()> {
+        // Lines that aren't redrawn in place are left alone
+        if !self.mode.is_animated() {
+            return Ok(());
+        }
+
+        write!(w, "\r\x1b[2K")?;
+        w.flush()
+    }
+
+    /// Shows or hides the cursor, if the spinner is meant to hide it while running
+    pub fn show_cursor(&self, w: &mut dyn Write, visible: bool) -> io::Result<()> {
+        if !self.hide_cursor || !self.mode.is_animated() {
+            return Ok(());
+        }
+
+        write!(w, "{}", if visible { "\x1b[?25h" } else { "\x1b[?25l" })?;
+        w.flush()
+    }
+}
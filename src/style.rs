@@ -0,0 +1,306 @@
+use std::{borrow::Cow, fmt};
+
+use crate::Spinners;
+
+/// The frames and default speed a [`Spinner`](crate::Spinner) is drawn with
+///
+/// Either one of the built in [`Spinners`] or a [`CustomSpinner`]. Anything that takes a spinner style accepts both, so this rarely needs to be named.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum SpinnerStyle {
+    /// One of the built in spinners
+    Builtin(Spinners),
+    /// A spinner with frames of its own
+    Custom(CustomSpinner),
+}
+
+impl SpinnerStyle {
+    /// The time between frames in milliseconds, unless set otherwise
+    ///
+    /// Built in spinners run through all of their frames once a second.
+    pub fn interval(&self) -> u64 {
+        match self {
+            SpinnerStyle::Builtin(spinner) => 1000 / spinner.get_frames().len() as u64,
+            SpinnerStyle::Custom(custom) => custom.interval,
+        }
+    }
+
+    /// The frames to draw, without copying the built in ones
+    pub(crate) fn frames(&self) -> Vec<Cow<'static, str>> {
+        match self {
+            SpinnerStyle::Builtin(spinner) => spinner
+                .get_frames()
+                .iter()
+                .map(|frame| Cow::Borrowed(*frame))
+                .collect(),
+            SpinnerStyle::Custom(custom) => custom
+                .frames
+                .iter()
+                .map(|frame| Cow::Owned(frame.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for SpinnerStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpinnerStyle::Builtin(spinner) => write!(f, "{}", spinner),
+            SpinnerStyle::Custom(custom) => write!(f, "{}", custom.name),
+        }
+    }
+}
+
+/// Either the name of a built in spinner or a custom spinner written out in full
+///
+/// This is done by hand rather than derived, so a misspelt name still suggests the closest spinners.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SpinnerStyle {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Name(String),
+            Custom(CustomSpinner),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Name(name) => name
+                .parse::<Spinners>()
+                .map(SpinnerStyle::Builtin)
+                .map_err(serde::de::Error::custom),
+            Repr::Custom(custom) => Ok(SpinnerStyle::Custom(custom)),
+        }
+    }
+}
+
+impl PartialEq<Spinners> for SpinnerStyle {
+    fn eq(&self, other: &Spinners) -> bool {
+        matches!(self, SpinnerStyle::Builtin(spinner) if spinner == other)
+    }
+}
+
+impl From<Spinners> for SpinnerStyle {
+    fn from(spinner: Spinners) -> Self {
+        SpinnerStyle::Builtin(spinner)
+    }
+}
+
+impl From<CustomSpinner> for SpinnerStyle {
+    fn from(custom: CustomSpinner) -> Self {
+        SpinnerStyle::Custom(custom)
+    }
+}
+
+/// A spinner with its own frames, usually put together from the built in ones
+///
+/// Start from any of the [`Spinners`] and combine it with others using [`CustomSpinner::zip`], [`CustomSpinner::then`] and [`CustomSpinner::hold`].
+/// The result can be used anywhere a [`Spinners`] value can, such as [`Spinner::new`](crate::Spinner::new) and [`Spinner::set_spinner`](crate::Spinner::set_spinner).
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::{CustomSpinner, Spinner, Spinners};
+///
+/// // Three dots side by side, each a few frames ahead of the one before
+/// let wave = CustomSpinner::from(Spinners::Dots)
+///     .zip(Spinners::Dots, 3)
+///     .zip(Spinners::Dots, 6);
+///
+/// assert_eq!(wave.frames()[0], "⠋⠸⠦");
+///
+/// let mut sp = Spinner::new(wave, "Waving...");
+/// sp.start();
+/// sp.stop();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct CustomSpinner {
+    name: String,
+    frames: Vec<String>,
+    interval: u64,
+}
+
+/// The lowest common multiple, so that zipped spinners both get to finish their cycle
+fn lcm(a: usize, b: usize) -> usize {
+    fn gcd(a: usize, b: usize) -> usize {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    if a == 0 || b == 0 {
+        a.max(b)
+    } else {
+        a / gcd(a, b) * b
+    }
+}
+
+impl CustomSpinner {
+    /// Creates a spinner from scratch, with the given frames and time between them in milliseconds
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{CustomSpinner, Spinner};
+    ///
+    /// let pulse = CustomSpinner::new("pulse", ["·", "•", "●", "•"], 150);
+    ///
+    /// let mut sp = Spinner::new(pulse, "Thinking...");
+    /// sp.start();
+    /// sp.stop();
+    /// ```
+    pub fn new(
+        name: impl fmt::Display,
+        frames: impl IntoIterator<Item = impl fmt::Display>,
+        interval: u64,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            frames: frames.into_iter().map(|frame| frame.to_string()).collect(),
+            interval,
+        }
+    }
+
+    /// The name of the spinner, as returned by [`Spinner::get_name`](crate::Spinner::get_name)
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The frames of the spinner, in order
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    /// The time between frames in milliseconds, unless set otherwise
+    pub fn interval(&self) -> u64 {
+        self.interval
+    }
+
+    /// Renames the spinner
+    pub fn with_name(mut self, name: impl fmt::Display) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Sets the time between frames in milliseconds
+    ///
+    /// Combined spinners keep the interval of the one they started from.
+    pub fn with_interval(mut self, interval: u64) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Draws another spinner right after this one, `offset` frames ahead of it
+    ///
+    /// When the spinners have a different number of frames, the result runs until both line up again.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{CustomSpinner, Spinners};
+    ///
+    /// let arrows = CustomSpinner::from(Spinners::Arrow).zip(Spinners::Arrow, 4);
+    ///
+    /// assert_eq!(arrows.frames()[0], "←→");
+    /// assert_eq!(arrows.frames().len(), 8);
+    /// ```
+    pub fn zip(self, other: impl Into<CustomSpinner>, offset: usize) -> Self {
+        let other = other.into();
+        let len = lcm(self.frames.len(), other.frames.len());
+
+        let frames = (0..len)
+            .map(|i| {
+                let left = self.frames.get(i % self.frames.len().max(1));
+                let right = other.frames.get((i + offset) % other.frames.len().max(1));
+
+                format!(
+                    "{}{}",
+                    left.map(String::as_str).unwrap_or_default(),
+                    right.map(String::as_str).unwrap_or_default()
+                )
+            })
+            .collect();
+
+        Self {
+            name: format!("{}+{}", self.name, other.name),
+            frames,
+            interval: self.interval,
+        }
+    }
+
+    /// Plays another spinner once this one has shown all of its frames
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{CustomSpinner, Spinners};
+    ///
+    /// let arrows = CustomSpinner::from(Spinners::Arrow).then(Spinners::Arrow3);
+    ///
+    /// assert_eq!(arrows.frames()[7], "↙");
+    /// assert_eq!(arrows.frames()[8], "▹▹▹▹▹");
+    /// ```
+    pub fn then(mut self, other: impl Into<CustomSpinner>) -> Self {
+        let other = other.into();
+
+        self.name = format!("{}>{}", self.name, other.name);
+        self.frames.extend(other.frames);
+        self
+    }
+
+    /// Shows the frame at `index` for `times` frames in a row, slowing down that part of the animation
+    ///
+    /// Nothing changes if there is no frame at `index`. A frame is always shown at least once.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::{CustomSpinner, Spinners};
+    ///
+    /// // Linger on the full bar before starting over
+    /// let bar = CustomSpinner::from(Spinners::Arrow3).hold(5, 4);
+    ///
+    /// assert_eq!(bar.frames().len(), 9);
+    /// assert_eq!(bar.frames()[8], "▹▹▹▹▸");
+    /// ```
+    pub fn hold(mut self, index: usize, times: usize) -> Self {
+        if let Some(frame) = self.frames.get(index).cloned() {
+            let repeated = std::iter::repeat(frame).take(times.max(1) - 1);
+            self.frames.splice(index..index, repeated);
+        }
+
+        self
+    }
+}
+
+impl From<Spinners> for CustomSpinner {
+    fn from(spinner: Spinners) -> Self {
+        let style = SpinnerStyle::from(spinner);
+
+        Self {
+            name: spinner.to_string(),
+            frames: spinner
+                .get_frames()
+                .iter()
+                .map(|frame| frame.to_string())
+                .collect(),
+            interval: style.interval(),
+        }
+    }
+}
+
+impl From<SpinnerStyle> for CustomSpinner {
+    fn from(style: SpinnerStyle) -> Self {
+        match style {
+            SpinnerStyle::Builtin(spinner) => spinner.into(),
+            SpinnerStyle::Custom(custom) => custom,
+        }
+    }
+}
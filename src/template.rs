@@ -0,0 +1,90 @@
+/// A piece of a [`Template`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Text(String),
+    Prefix,
+    Frame,
+    Message,
+    Suffix,
+}
+
+/// The layout of the spinner line, given to [`SpinnerBuilder::template`](crate::SpinnerBuilder::template)
+///
+/// `{prefix}`, `{frame}`, `{message}` and `{suffix}` are replaced with the parts of the line, and `{{` and `}}` stand for plain braces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Template {
+    segments: Vec<Segment>,
+}
+
+impl Template {
+    /// Reads a template, explaining what is wrong with it if it can't be
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        let mut chars = template.chars();
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '{' if chars.as_str().starts_with('{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '}' if chars.as_str().starts_with('}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '{' => {
+                    let rest = chars.as_str();
+                    let end = rest
+                        .find('}')
+                        .ok_or_else(|| format!("unclosed `{{` in the template `{}`", template))?;
+
+                    let segment = match &rest[..end] {
+                        "prefix" => Segment::Prefix,
+                        "frame" => Segment::Frame,
+                        "message" => Segment::Message,
+                        "suffix" => Segment::Suffix,
+                        name => {
+                            return Err(format!(
+                                "unknown placeholder `{{{}}}` in the template, expected `{{prefix}}`, `{{frame}}`, `{{message}}` or `{{suffix}}`",
+                                name
+                            ))
+                        }
+                    };
+
+                    if !text.is_empty() {
+                        segments.push(Segment::Text(std::mem::take(&mut text)));
+                    }
+                    segments.push(segment);
+                    chars = rest[end + 1..].chars();
+                }
+                '}' => return Err(format!("unmatched `}}` in the template `{}`", template)),
+                '\n' | '\r' => return Err("the template can't span several lines".to_string()),
+                ch => text.push(ch),
+            }
+        }
+
+        if !text.is_empty() {
+            segments.push(Segment::Text(text));
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Fills in the template, trimming the spaces left around parts that are empty
+    pub fn render(&self, prefix: &str, frame: &str, message: &str, suffix: &str) -> String {
+        let line = self
+            .segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Text(text) => text.as_str(),
+                Segment::Prefix => prefix,
+                Segment::Frame => frame,
+                Segment::Message => message,
+                Segment::Suffix => suffix,
+            })
+            .collect::<String>();
+
+        line.trim().to_string()
+    }
+}
@@ -0,0 +1,392 @@
+use std::{
+    fmt,
+    io::{self, Write},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use crate::Output;
+
+/// An in-memory terminal, for testing what spinners leave on screen
+///
+/// Everything written to it is kept, and carriage returns, new lines, erase sequences and cursor movements are played out on a virtual screen.
+/// Use [`TestTerminal::output`] to draw a spinner to it, along with a [`ManualClock`](crate::ManualClock) to decide exactly when each frame is drawn.
+///
+/// Every character takes up a single cell and the screen never scrolls or wraps, so lines are as long as what was written to them.
+/// Colors and other sequences that don't move the cursor or change the text are ignored.
+///
+/// Clones share the same screen, so one can be given to the spinner and another kept to look at it.
+///
+/// # Example:
+///
+/// ```
+/// use spinners_rs::{ManualClock, Mode, Spinner, Spinners, TestTerminal};
+/// use std::time::Duration;
+///
+/// let clock = ManualClock::new();
+/// let terminal = TestTerminal::new();
+///
+/// let mut sp = Spinner::builder()
+///     .spinner(Spinners::Line)
+///     .message("Downloading")
+///     .interval(100)
+///     .mode(Mode::Animated)
+///     .env_overrides(false)
+///     .clock(clock.clone())
+///     .output(terminal.output())
+///     .start()
+///     .unwrap();
+///
+/// clock.advance(Duration::from_millis(100));
+/// sp.set_message("Downloading the internet");
+/// sp.stop_with_success();
+///
+/// terminal.assert_states(&[
+///     "- Downloading",
+///     "\\ Downloading",
+///     "\\ Downloading the internet",
+///     "✔ Downloading the internet",
+/// ]);
+/// ```
+#[derive(Clone, Default)]
+pub struct TestTerminal {
+    inner: Arc<Mutex<Screen>>,
+}
+
+/// Everything written to a [`TestTerminal`], and what it looks like
+struct Screen {
+    rows: Vec<Vec<char>>,
+    row: usize,
+    col: usize,
+    cursor_visible: bool,
+    written: Vec<u8>,
+    /// Bytes that can't be played out yet, such as half of a character or escape sequence
+    pending: Vec<u8>,
+    states: Vec<String>,
+}
+
+impl Default for Screen {
+    fn default() -> Self {
+        Self {
+            rows: Vec::new(),
+            row: 0,
+            col: 0,
+            cursor_visible: true,
+            written: Vec::new(),
+            pending: Vec::new(),
+            states: Vec::new(),
+        }
+    }
+}
+
+impl Screen {
+    /// The row the cursor is on, making sure it exists
+    fn line(&mut self) -> &mut Vec<char> {
+        if self.rows.len() <= self.row {
+            self.rows.resize_with(self.row + 1, Vec::new);
+        }
+
+        &mut self.rows[self.row]
+    }
+
+    fn put(&mut self, ch: char) {
+        let col = self.col;
+        let line = self.line();
+
+        if line.len() <= col {
+            line.resize(col + 1, ' ');
+        }
+
+        line[col] = ch;
+        self.col += 1;
+    }
+
+    /// Plays out as much of the pending bytes as possible
+    fn play(&mut self) {
+        let mut start = 0;
+
+        while start < self.pending.len() {
+            let rest = &self.pending[start..];
+
+            let used = match rest[0] {
+                0x1b => match self.escape(start) {
+                    Some(used) => used,
+                    None => break,
+                },
+                byte => {
+                    let len = match byte {
+                        0xf0..=0xf7 => 4,
+                        0xe0..=0xef => 3,
+                        0xc0..=0xdf => 2,
+                        _ => 1,
+                    };
+
+                    if rest.len() < len {
+                        break;
+                    }
+
+                    match std::str::from_utf8(&rest[..len]) {
+                        Ok(s) => {
+                            let ch = s.chars().next().unwrap_or_default();
+                            self.control_or_put(ch);
+                            len
+                        }
+                        Err(_) => {
+                            self.put(char::REPLACEMENT_CHARACTER);
+                            1
+                        }
+                    }
+                }
+            };
+
+            start += used;
+        }
+
+        self.pending.drain(..start);
+    }
+
+    fn control_or_put(&mut self, ch: char) {
+        match ch {
+            '\r' => self.col = 0,
+            '\n' => {
+                self.row += 1;
+                self.col = 0;
+                self.line();
+            }
+            '\x08' => self.col = self.col.saturating_sub(1),
+            '\t' => self.col = (self.col / 8 + 1) * 8,
+            ch if ch.is_control() => {}
+            ch => self.put(ch),
+        }
+    }
+
+    /// Plays out the escape sequence starting at the given pending byte, returning its length if it is complete
+    fn escape(&mut self, start: usize) -> Option<usize> {
+        let rest = &self.pending[start..];
+
+        if rest.len() < 2 {
+            return None;
+        }
+
+        // Only control sequences do anything, anything else is skipped along with the byte after the escape
+        if rest[1] != b'[' {
+            return Some(2);
+        }
+
+        let end = 2 + rest[2..].iter().position(|b| (0x40..=0x7e).contains(b))?;
+        let params = String::from_utf8_lossy(&rest[2..end]).into_owned();
+        let command = rest[end] as char;
+
+        self.control(&params, command);
+
+        Some(end + 1)
+    }
+
+    /// Carries out a control sequence, such as `2K` to erase the line
+    fn control(&mut self, params: &str, command: char) {
+        if let Some(private) = params.strip_prefix('?') {
+            match (private, command) {
+                ("25", 'h') => self.cursor_visible = true,
+                ("25", 'l') => self.cursor_visible = false,
+                _ => {}
+            }
+            return;
+        }
+
+        let args = params
+            .split(';')
+            .map(|arg| arg.parse::<usize>().ok())
+            .collect::<Vec<_>>();
+        let arg = |i: usize, default: usize| args.get(i).copied().flatten().unwrap_or(default);
+        // Moving by zero moves by one
+        let by = arg(0, 1).max(1);
+
+        match command {
+            'A' => self.row = self.row.saturating_sub(by),
+            'B' => self.row += by,
+            'C' => self.col += by,
+            'D' => self.col = self.col.saturating_sub(by),
+            'E' => (self.row, self.col) = (self.row + by, 0),
+            'F' => (self.row, self.col) = (self.row.saturating_sub(by), 0),
+            'G' => self.col = by - 1,
+            'H' | 'f' => (self.row, self.col) = (arg(0, 1).max(1) - 1, arg(1, 1).max(1) - 1),
+            'K' => self.erase_line(arg(0, 0)),
+            'J' => self.erase_display(arg(0, 0)),
+            _ => {}
+        }
+    }
+
+    fn erase_line(&mut self, mode: usize) {
+        let col = self.col;
+        let line = self.line();
+
+        match mode {
+            0 => line.truncate(col),
+            1 => line.iter_mut().take(col + 1).for_each(|cell| *cell = ' '),
+            _ => line.clear(),
+        }
+    }
+
+    fn erase_display(&mut self, mode: usize) {
+        match mode {
+            0 => {
+                self.erase_line(0);
+                self.rows.truncate(self.row + 1);
+            }
+            1 => {
+                self.erase_line(1);
+                self.rows.iter_mut().take(self.row).for_each(Vec::clear);
+            }
+            _ => self.rows.clear(),
+        }
+    }
+
+    fn lines(&self) -> Vec<String> {
+        let mut lines = self
+            .rows
+            .iter()
+            .map(|row| row.iter().collect::<String>().trim_end().to_string())
+            .collect::<Vec<_>>();
+
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+
+        lines
+    }
+
+    fn screen(&self) -> String {
+        self.lines().join("\n")
+    }
+}
+
+impl TestTerminal {
+    /// Creates an empty terminal
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Screen> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// An output that draws to this terminal, to give to [`SpinnerBuilder::output`](crate::SpinnerBuilder::output)
+    pub fn output(&self) -> Output {
+        Output::writer(self.clone())
+    }
+
+    /// What is on screen, with trailing spaces and empty lines left out
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use spinners_rs::TestTerminal;
+    /// use std::io::Write;
+    ///
+    /// let mut terminal = TestTerminal::new();
+    ///
+    /// write!(terminal, "Loading...\rDone\x1b[K\n").unwrap();
+    ///
+    /// assert_eq!(terminal.screen(), "Done");
+    /// ```
+    pub fn screen(&self) -> String {
+        self.lock().screen()
+    }
+
+    /// The lines on screen, with trailing spaces and empty lines left out
+    pub fn lines(&self) -> Vec<String> {
+        self.lock().lines()
+    }
+
+    /// What was on screen every time the output was flushed, leaving out flushes that didn't change anything
+    ///
+    /// Spinners flush once they have drawn something, so this is every distinct frame and message that was shown, in order.
+    pub fn states(&self) -> Vec<String> {
+        self.lock().states.clone()
+    }
+
+    /// Everything written to the terminal, without playing it out
+    pub fn written(&self) -> String {
+        String::from_utf8_lossy(&self.lock().written).into_owned()
+    }
+
+    /// The row and column of the cursor, both starting at zero
+    pub fn cursor(&self) -> (usize, usize) {
+        let screen = self.lock();
+        (screen.row, screen.col)
+    }
+
+    /// Whether the cursor is shown, which spinners that hide it while running should leave as `true` once stopped
+    pub fn is_cursor_visible(&self) -> bool {
+        self.lock().cursor_visible
+    }
+
+    /// Checks that the screen looks as expected, see [`TestTerminal::screen`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the screen is different, showing both side by side.
+    #[track_caller]
+    pub fn assert_screen(&self, expected: &str) {
+        let screen = self.screen();
+
+        if screen != expected {
+            panic!(
+                "the screen doesn't match\n\n--- expected ---\n{}\n--- actual ---\n{}\n---\n\nwritten: {:?}",
+                expected,
+                screen,
+                self.written()
+            );
+        }
+    }
+
+    /// Checks that the screen went through the expected states, see [`TestTerminal::states`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the states are different, showing both.
+    #[track_caller]
+    pub fn assert_states(&self, expected: &[&str]) {
+        let states = self.states();
+
+        if states != expected {
+            panic!(
+                "the screen states don't match\n\n--- expected ---\n{:#?}\n--- actual ---\n{:#?}\n---\n\nwritten: {:?}",
+                expected,
+                states,
+                self.written()
+            );
+        }
+    }
+}
+
+impl Write for TestTerminal {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut screen = self.lock();
+
+        screen.written.extend_from_slice(buf);
+        screen.pending.extend_from_slice(buf);
+        screen.play();
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let mut screen = self.lock();
+        let current = screen.screen();
+
+        // Flushing before anything was drawn isn't a state of its own
+        if screen.states.last().map_or("", String::as_str) != current {
+            screen.states.push(current);
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for TestTerminal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TestTerminal")
+            .field("screen", &self.screen())
+            .finish()
+    }
+}
@@ -0,0 +1,8 @@
+/// An environment made of the given variables, to pass where the real one would be read
+pub(crate) fn lookup<'a>(vars: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+    move |name| {
+        vars.iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.to_string())
+    }
+}
@@ -0,0 +1,68 @@
+use spinners_rs::{CustomSpinner, Error, Mode, Spinner, SpinnerBuilder, Spinners};
+
+fn rejected(builder: SpinnerBuilder) -> String {
+    match builder.build() {
+        Err(Error::Config(reason)) => reason,
+        other => panic!("expected a configuration error, got {:?}", other),
+    }
+}
+
+#[test]
+fn rejects_a_zero_interval() {
+    let reason = rejected(Spinner::builder().interval(0));
+
+    assert_eq!(reason, "the interval must be at least 1ms");
+}
+
+#[test]
+fn rejects_spinners_without_frames() {
+    let empty = CustomSpinner::new("empty", Vec::<&str>::new(), 100);
+
+    assert_eq!(
+        rejected(Spinner::builder().spinner(empty)),
+        "the spinner `empty` has no frames"
+    );
+}
+
+#[test]
+fn rejects_text_spanning_several_lines() {
+    assert_eq!(
+        rejected(Spinner::builder().message("Loading\nfiles")),
+        "the message can't span several lines"
+    );
+    assert_eq!(
+        rejected(Spinner::builder().suffix("\r")),
+        "the suffix can't span several lines"
+    );
+    assert_eq!(
+        rejected(Spinner::builder().failure_symbol("x\n")),
+        "the failure symbol can't span several lines"
+    );
+}
+
+#[test]
+fn rejects_templates_that_cant_be_read() {
+    assert!(rejected(Spinner::builder().template("{spinner}"))
+        .contains("unknown placeholder `{spinner}`"));
+    assert!(rejected(Spinner::builder().template("{frame")).contains("unclosed `{`"));
+    assert!(rejected(Spinner::builder().template("frame}")).contains("unmatched `}`"));
+
+    assert!(Spinner::builder()
+        .spinner(Spinners::Line)
+        .template("{{{frame}}} {message}")
+        .build()
+        .is_ok());
+}
+
+#[test]
+fn rejects_a_zero_heartbeat() {
+    assert_eq!(
+        rejected(Spinner::builder().mode(Mode::Heartbeat(0))),
+        "the heartbeat must be at least 1ms"
+    );
+
+    let mut sp = Spinner::new(Spinners::Line, "Loading");
+    sp.set_mode(Mode::Heartbeat(0));
+    assert!(matches!(sp.try_start(), Err(Error::Config(_))));
+    assert!(!sp.is_running());
+}
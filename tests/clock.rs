@@ -0,0 +1,222 @@
+mod common;
+
+use std::{
+    io::{self, Write},
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use common::{builder, ms};
+use spinners_rs::{Clock, ManualClock, Mode, Output, Spinner, Spinners, TestTerminal};
+
+/// Collects everything a spinner writes
+#[derive(Debug, Clone, Default)]
+struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+impl Buffer {
+    fn output(&self) -> Output {
+        Output::writer(self.clone())
+    }
+
+    /// Takes what was written since the last call
+    fn take(&self) -> String {
+        String::from_utf8(std::mem::take(&mut *self.0.lock().unwrap())).unwrap()
+    }
+}
+
+impl Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn draws_first_frame_on_start() {
+    let clock = ManualClock::new();
+    let buffer = Buffer::default();
+
+    let mut sp = builder(&clock, buffer.output()).start().unwrap();
+
+    assert_eq!(buffer.take(), "\r- Loading\x1b[K");
+
+    sp.stop();
+}
+
+#[test]
+fn draws_each_frame_as_it_comes_due() {
+    let clock = ManualClock::new();
+    let buffer = Buffer::default();
+
+    let mut sp = builder(&clock, buffer.output()).start().unwrap();
+    buffer.take();
+
+    clock.advance(ms(99));
+    assert_eq!(buffer.take(), "");
+
+    clock.advance(ms(1));
+    assert_eq!(buffer.take(), "\r\\ Loading\x1b[K");
+
+    // Frames skipped over by a big step are still drawn, in order
+    clock.advance(ms(300));
+    assert_eq!(
+        buffer.take(),
+        "\r| Loading\x1b[K\r/ Loading\x1b[K\r- Loading\x1b[K"
+    );
+
+    sp.stop();
+}
+
+#[test]
+fn draws_messages_straight_away() {
+    let clock = ManualClock::new();
+    let buffer = Buffer::default();
+
+    let mut sp = builder(&clock, buffer.output()).start().unwrap();
+    clock.advance(ms(150));
+    buffer.take();
+
+    sp.set_message("Saving");
+    assert_eq!(buffer.take(), "\r\\ Saving\x1b[K");
+
+    // The change doesn't move the next frame
+    clock.advance(ms(50));
+    assert_eq!(buffer.take(), "\r| Saving\x1b[K");
+
+    sp.stop();
+}
+
+#[test]
+fn pausing_stops_time() {
+    let clock = ManualClock::new();
+    let buffer = Buffer::default();
+
+    let mut sp = builder(&clock, buffer.output()).start().unwrap();
+    clock.advance(ms(100));
+    buffer.take();
+
+    sp.pause();
+    assert_eq!(buffer.take(), "\r\x1b[2K");
+
+    clock.advance(ms(1000));
+    assert_eq!(buffer.take(), "");
+
+    sp.resume();
+    assert_eq!(buffer.take(), "\r\\ Loading\x1b[K");
+    assert_eq!(sp.current_frame(clock.now()), "\\");
+
+    sp.stop();
+}
+
+#[test]
+fn built_spinners_start_by_their_own_clock() {
+    let clock = ManualClock::new();
+    clock.advance(ms(1000));
+
+    let sp = builder(&clock, Output::Stdout).build().unwrap();
+    assert_eq!(sp.current_frame(clock.now()), "-");
+
+    clock.advance(ms(100));
+    assert_eq!(sp.current_frame(clock.now()), "\\");
+}
+
+#[test]
+fn stopping_writes_the_final_line_last() {
+    let clock = ManualClock::new();
+    let buffer = Buffer::default();
+
+    let mut sp = builder(&clock, buffer.output()).start().unwrap();
+    buffer.take();
+
+    sp.stop_with_success();
+    assert_eq!(buffer.take(), "\r✔ Loading\x1b[K");
+
+    clock.advance(ms(1000));
+    assert_eq!(buffer.take(), "");
+    assert!(!sp.is_running());
+}
+
+#[test]
+fn heartbeats_come_at_exact_times() {
+    let clock = ManualClock::new();
+    let buffer = Buffer::default();
+
+    let mut sp = builder(&clock, buffer.output())
+        .mode(Mode::Heartbeat(60_000))
+        .start()
+        .unwrap();
+
+    clock.advance(ms(150_000));
+    sp.stop_with_success();
+
+    assert_eq!(
+        buffer.take(),
+        "Loading\n\
+         still running: Loading (1m0s)\n\
+         still running: Loading (2m0s)\n\
+         ✔ Loading\n"
+    );
+}
+
+#[test]
+fn spinners_share_a_clock() {
+    let clock = ManualClock::new();
+    let first = Buffer::default();
+    let second = Buffer::default();
+
+    let mut a = builder(&clock, first.output()).start().unwrap();
+    let mut b = builder(&clock, second.output())
+        .interval(250)
+        .start()
+        .unwrap();
+    first.take();
+    second.take();
+
+    clock.advance(ms(500));
+
+    assert_eq!(first.take().matches('\r').count(), 5);
+    assert_eq!(second.take(), "\r\\ Loading\x1b[K\r| Loading\x1b[K");
+
+    a.stop();
+    b.stop();
+}
+
+/// A clock that is always an hour ahead
+#[derive(Debug)]
+struct Ahead;
+
+impl Clock for Ahead {
+    fn now(&self) -> Instant {
+        Instant::now() + Duration::from_secs(3600)
+    }
+}
+
+#[test]
+fn the_render_thread_follows_custom_clocks() {
+    let terminal = TestTerminal::new();
+
+    let mut sp = Spinner::builder()
+        .spinner(Spinners::Line)
+        .message("Loading")
+        .interval(20)
+        .mode(Mode::Animated)
+        .env_overrides(false)
+        .output(terminal.output())
+        .clock(Ahead)
+        .start()
+        .unwrap();
+
+    thread::sleep(Duration::from_millis(300));
+    sp.stop();
+
+    // Without taking the clock into account, the second frame would be due in an hour
+    assert!(
+        terminal.states().len() >= 4,
+        "only drew {:?}",
+        terminal.states()
+    );
+}
@@ -0,0 +1,22 @@
+use std::time::Duration;
+
+use spinners_rs::{AsciiFallback, ManualClock, Mode, Output, Spinner, SpinnerBuilder, Spinners};
+
+/// A spinner drawn by a manual clock, unaffected by the environment the tests run in
+pub fn builder(clock: &ManualClock, output: Output) -> SpinnerBuilder {
+    Spinner::builder()
+        .spinner(Spinners::Line)
+        .message("Loading")
+        .interval(100)
+        .mode(Mode::Animated)
+        .env_overrides(false)
+        .fallback(AsciiFallback::never())
+        .output(output)
+        .clock(clock.clone())
+}
+
+// Not every test file moves the clock along
+#[allow(dead_code)]
+pub fn ms(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+}
@@ -0,0 +1,45 @@
+//! Spinners with the animation turned off through the environment
+//!
+//! The environment is shared by the whole process, so this is kept to a single test in a file of its own.
+
+mod common;
+
+use std::env;
+
+use common::{builder, ms};
+use spinners_rs::{DropBehavior, ManualClock, TestTerminal, ENV_ENABLED};
+
+#[test]
+fn only_the_final_line_is_written_when_turned_off() {
+    env::set_var(ENV_ENABLED, "off");
+
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output())
+        .env_overrides(true)
+        .start()
+        .unwrap();
+
+    assert!(sp.is_running());
+    clock.advance(ms(500));
+    sp.set_message("Saving");
+    sp.pause();
+    sp.resume();
+    assert_eq!(terminal.written(), "");
+
+    sp.stop_with_success();
+    assert!(!sp.is_running());
+    terminal.assert_screen("✔ Saving");
+
+    // Dropping still shows what became of the spinner
+    let terminal = TestTerminal::new();
+    let sp = builder(&clock, terminal.output())
+        .env_overrides(true)
+        .drop_behavior(DropBehavior::Fail)
+        .start()
+        .unwrap();
+    drop(sp);
+
+    terminal.assert_screen("✖ Loading");
+}
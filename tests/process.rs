@@ -0,0 +1,39 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::process::Command;
+
+use common::builder;
+use spinners_rs::{ManualClock, TestTerminal};
+
+#[test]
+fn output_lines_show_only_what_a_terminal_would_leave() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output()).build().unwrap();
+
+    let output = sp
+        .run_command(
+            Command::new("printf").arg("progress 10%%\\rprogress 90%%\\r\\033[1mfinal\\033[0m\\n"),
+            true,
+        )
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let command = r"printf 'progress 10%%\rprogress 90%%\r\033[1mfinal\033[0m\n'";
+    let states = terminal.states();
+
+    assert!(states.contains(&format!("- {}: final", command)));
+    assert_eq!(states.last().unwrap(), &format!("✔ {}", command));
+
+    for state in &states {
+        assert!(
+            state.contains(command),
+            "lost the command line: {:?}",
+            state
+        );
+    }
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "serde")]
+
+use spinners_rs::{Animation, SpinnerBuilder};
+
+#[test]
+fn options_and_values_are_both_camel_case() {
+    let builder = SpinnerBuilder::new()
+        .hide_cursor(true)
+        .animation(Animation::PingPong);
+
+    let config = toml::to_string(&builder).unwrap();
+
+    assert!(config.contains("hideCursor = true"));
+    assert!(config.contains("successSymbol = \"✔\""));
+    assert!(config.contains("dropBehavior = \"persist\""));
+    assert!(config.contains("animation = \"pingPong\""));
+    assert!(!config.contains('_'));
+}
+
+#[test]
+fn configs_read_back_the_same() {
+    let builder = SpinnerBuilder::new()
+        .message("Loading")
+        .hide_cursor(true)
+        .env_overrides(false);
+
+    let config = toml::to_string(&builder).unwrap();
+    let read = toml::from_str::<SpinnerBuilder>(&config).unwrap();
+
+    assert_eq!(toml::to_string(&read).unwrap(), config);
+}
@@ -0,0 +1,362 @@
+mod common;
+
+use std::{
+    io::{self, Write},
+    thread,
+};
+
+use common::{builder, ms};
+use spinners_rs::{
+    AsciiFallback, CustomSpinner, DropBehavior, Error, ManualClock, Mode, Output, Spinner,
+    Spinners, TestTerminal,
+};
+
+/// A writer that can't be written to
+struct Broken;
+
+impl Write for Broken {
+    fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+        Err(io::ErrorKind::Other.into())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn starting_twice_leaves_nothing_drawing_once_stopped() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output()).build().unwrap();
+    sp.start();
+    clock.advance(ms(100));
+    sp.start();
+    sp.stop();
+
+    let written = terminal.written();
+    clock.advance(ms(1000));
+
+    assert_eq!(terminal.written(), written);
+    assert!(!sp.is_running());
+}
+
+#[test]
+fn starting_again_starts_over() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output()).start().unwrap();
+    clock.advance(ms(200));
+    sp.start();
+
+    terminal.assert_screen("- Loading");
+
+    clock.advance(ms(100));
+    terminal.assert_screen("\\ Loading");
+
+    sp.stop();
+}
+
+#[test]
+fn errors_keep_being_returned_until_started_again() {
+    let clock = ManualClock::new();
+
+    let mut sp = builder(&clock, Output::writer(Broken)).start().unwrap();
+
+    assert!(matches!(sp.try_set_message("First"), Err(Error::Io(_))));
+    assert!(!sp.is_running());
+
+    assert!(matches!(
+        sp.try_set_message("Second"),
+        Err(Error::Disconnected)
+    ));
+    assert!(matches!(sp.try_pause(), Err(Error::Disconnected)));
+    assert!(matches!(sp.try_stop(), Err(Error::Disconnected)));
+    assert!(matches!(sp.try_stop(), Err(Error::Disconnected)));
+
+    // Starting again tries to draw the spinner again, which fails the same way
+    sp.start();
+    assert!(matches!(sp.try_set_message("Third"), Err(Error::Io(_))));
+}
+
+#[test]
+fn stopped_spinners_can_still_be_updated() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output()).start().unwrap();
+    sp.stop();
+
+    assert!(sp.try_set_message("Saving").is_ok());
+    assert_eq!(sp.get_message(), "Saving");
+}
+
+#[test]
+fn dropping_leaves_the_last_frame_by_default() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let sp = builder(&clock, terminal.output()).start().unwrap();
+    clock.advance(ms(100));
+    drop(sp);
+
+    let written = terminal.written();
+    clock.advance(ms(1000));
+
+    terminal.assert_screen("\\ Loading");
+    assert_eq!(terminal.written(), written);
+}
+
+#[test]
+fn dropping_can_clear_the_line() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let sp = builder(&clock, terminal.output())
+        .drop_behavior(DropBehavior::Clear)
+        .start()
+        .unwrap();
+    drop(sp);
+
+    terminal.assert_screen("");
+}
+
+#[test]
+fn dropping_can_fail_the_spinner() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let sp = builder(&clock, terminal.output())
+        .drop_behavior(DropBehavior::Fail)
+        .start()
+        .unwrap();
+    drop(sp);
+
+    terminal.assert_screen("✖ Loading");
+}
+
+#[test]
+fn dropping_while_panicking_fails_the_spinner() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let sp = builder(&clock, terminal.output())
+        .drop_behavior(DropBehavior::FailOnPanic)
+        .start()
+        .unwrap();
+    drop(sp);
+
+    terminal.assert_screen("- Loading");
+
+    let output = terminal.output();
+    let panicked = thread::spawn(move || {
+        let _sp = builder(&clock, output)
+            .message("Saving")
+            .drop_behavior(DropBehavior::FailOnPanic)
+            .start()
+            .unwrap();
+
+        panic!("saving failed");
+    })
+    .join();
+
+    assert!(panicked.is_err());
+    terminal.assert_screen("✖ Saving");
+}
+
+#[test]
+fn dropping_a_stopped_spinner_draws_nothing() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output())
+        .drop_behavior(DropBehavior::Fail)
+        .start()
+        .unwrap();
+    sp.stop_with_success();
+    drop(sp);
+
+    terminal.assert_screen("✔ Loading");
+}
+
+#[test]
+fn handles_can_outlive_their_spinner() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let sp = builder(&clock, terminal.output()).start().unwrap();
+    let handle = sp.handle();
+    drop(sp);
+
+    let written = terminal.written();
+
+    assert!(!handle.is_running());
+    assert!(handle.try_set_message("Saving").is_ok());
+    assert_eq!(handle.get_message(), "Saving");
+
+    clock.advance(ms(1000));
+    assert_eq!(terminal.written(), written);
+}
+
+#[test]
+fn dropping_handles_leaves_the_spinner_running() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output()).start().unwrap();
+
+    let handle = sp.handle();
+    thread::spawn(move || handle.set_message("Saving"))
+        .join()
+        .unwrap();
+
+    assert!(sp.is_running());
+    clock.advance(ms(100));
+    terminal.assert_screen("\\ Saving");
+
+    sp.stop();
+}
+
+#[test]
+fn spinners_that_cant_be_drawn_arent_started() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = Spinner::new(CustomSpinner::new("pulse", ["·", "•"], 0), "Loading");
+    assert!(matches!(sp.try_start(), Err(Error::Config(_))));
+    assert!(!sp.is_running());
+
+    let mut sp = builder(&clock, terminal.output()).build().unwrap();
+    assert!(matches!(sp.try_set_interval(0), Err(Error::Config(_))));
+    assert!(!sp.is_running());
+    terminal.assert_screen("");
+
+    let empty = CustomSpinner::new("empty", Vec::<&str>::new(), 100);
+    let result = builder(&clock, terminal.output()).spinner(empty).start();
+    assert!(matches!(result, Err(Error::Config(_))));
+}
+
+#[test]
+fn running_spinners_keep_their_interval_when_given_zero() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output()).start().unwrap();
+    let handle = sp.handle();
+
+    assert!(matches!(sp.try_set_interval(0), Err(Error::Config(_))));
+    assert!(matches!(handle.try_set_interval(0), Err(Error::Config(_))));
+
+    clock.advance(ms(100));
+    terminal.assert_screen("\\ Loading");
+
+    sp.stop();
+}
+
+#[test]
+fn running_spinners_can_switch_between_builtin_and_custom_frames() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output()).start().unwrap();
+
+    sp.set_spinner(CustomSpinner::new("pulse", ["·", "•"], 100));
+    terminal.assert_screen("· Loading");
+    assert!(sp.spinner != Spinners::Line);
+
+    sp.set_spinner(Spinners::Toggle);
+    terminal.assert_screen("⊶ Loading");
+    assert!(sp.spinner == Spinners::Toggle);
+
+    sp.stop();
+}
+
+#[test]
+fn ascii_fallback_can_be_turned_off() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output())
+        .spinner(Spinners::Dots)
+        .fallback(AsciiFallback::new().unicode(false))
+        .ascii_fallback(false)
+        .start()
+        .unwrap();
+    terminal.assert_screen("⠋ Loading");
+
+    sp.set_ascii_fallback(true);
+    sp.start();
+    terminal.assert_screen("- Loading");
+
+    sp.stop();
+}
+
+#[test]
+fn ascii_fallbacks_run_at_their_own_interval_unless_one_was_set() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+    let fallback =
+        AsciiFallback::new().default_spinner(CustomSpinner::new("pulse", ["·", "•"], 300));
+
+    let mut sp = Spinner::builder()
+        .spinner(Spinners::Dots)
+        .message("Loading")
+        .mode(Mode::Animated)
+        .env_overrides(false)
+        .fallback(fallback.clone().unicode(false))
+        .output(terminal.output())
+        .clock(clock.clone())
+        .start()
+        .unwrap();
+    terminal.assert_screen("· Loading");
+
+    clock.advance(ms(100));
+    terminal.assert_screen("· Loading");
+    clock.advance(ms(200));
+    terminal.assert_screen("• Loading");
+
+    sp.set_interval(100);
+    sp.start();
+    clock.advance(ms(100));
+    terminal.assert_screen("• Loading");
+    sp.stop();
+
+    let mut sp = builder(&clock, terminal.output())
+        .spinner(Spinners::Dots)
+        .fallback(fallback.unicode(false))
+        .start()
+        .unwrap();
+    clock.advance(ms(100));
+    terminal.assert_screen("• Loading");
+
+    sp.stop();
+}
+
+#[test]
+fn outcome_symbols_can_be_set() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output())
+        .success_symbol("OK")
+        .failure_symbol("ERR")
+        .start()
+        .unwrap();
+    sp.stop_with_success();
+    terminal.assert_screen("OK Loading");
+
+    sp.start();
+    sp.stop_with_failure();
+    terminal.assert_screen("ERR Loading");
+}
+
+#[test]
+fn names_are_capitalized_even_when_empty() {
+    let sp = Spinner::new(CustomSpinner::new("pulse", ["·", "•"], 100), "Loading");
+    assert_eq!(sp.get_name(), "Pulse");
+
+    let sp = Spinner::new(CustomSpinner::new("", ["·", "•"], 100), "Loading");
+    assert_eq!(sp.get_name(), "");
+}
@@ -0,0 +1,282 @@
+mod common;
+
+use std::io::Write;
+
+use common::{builder, ms};
+use spinners_rs::{Color, ManualClock, Mode, TestTerminal};
+
+#[test]
+fn carriage_returns_write_over_the_line() {
+    let mut terminal = TestTerminal::new();
+
+    write!(terminal, "Hello world\rJelly").unwrap();
+
+    terminal.assert_screen("Jelly world");
+    assert_eq!(terminal.cursor(), (0, 5));
+}
+
+#[test]
+fn erase_sequences_clear_parts_of_the_line() {
+    let mut terminal = TestTerminal::new();
+
+    write!(terminal, "Hello world\x1b[6D\x1b[K").unwrap();
+    terminal.assert_screen("Hello");
+
+    write!(terminal, "\x1b[1K").unwrap();
+    terminal.assert_screen("");
+
+    write!(terminal, "Hello\r\x1b[2K").unwrap();
+    terminal.assert_screen("");
+    assert_eq!(terminal.cursor(), (0, 0));
+}
+
+#[test]
+fn cursor_moves_between_lines() {
+    let mut terminal = TestTerminal::new();
+
+    write!(terminal, "one\ntwo\nthree\x1b[2A\x1b[1GONE\x1b[B\rTWO").unwrap();
+
+    assert_eq!(terminal.lines(), ["ONE", "TWO", "three"]);
+
+    write!(terminal, "\x1b[3;2H-\x1b[2J").unwrap();
+    terminal.assert_screen("");
+}
+
+#[test]
+fn sequences_can_be_split_between_writes() {
+    let mut terminal = TestTerminal::new();
+
+    terminal.write_all("Hello ⠋".as_bytes()).unwrap();
+    terminal.write_all(b"\r\x1b[").unwrap();
+    terminal.write_all(b"2K").unwrap();
+
+    let dots = "⠙".as_bytes();
+    terminal.write_all(&dots[..1]).unwrap();
+    terminal.write_all(&dots[1..]).unwrap();
+
+    terminal.assert_screen("⠙");
+}
+
+#[test]
+fn colors_are_ignored() {
+    let mut terminal = TestTerminal::new();
+
+    write!(terminal, "\x1b[1;32m✔\x1b[0m Done").unwrap();
+
+    terminal.assert_screen("✔ Done");
+}
+
+#[test]
+fn states_are_recorded_on_flush() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output()).start().unwrap();
+    clock.advance(ms(250));
+    sp.stop_with_success();
+
+    terminal.assert_states(&["- Loading", "\\ Loading", "| Loading", "✔ Loading"]);
+}
+
+#[test]
+fn shorter_messages_leave_nothing_behind() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output()).start().unwrap();
+
+    sp.set_message("Saving");
+    terminal.assert_screen("- Saving");
+
+    clock.advance(ms(100));
+    terminal.assert_screen("\\ Saving");
+
+    sp.stop();
+}
+
+#[test]
+fn stop_with_message_replaces_the_whole_line() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output()).start().unwrap();
+    sp.stop_with_message("Done");
+
+    terminal.assert_screen("Done");
+}
+
+#[test]
+fn stop_and_clear_leaves_an_empty_line() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output()).start().unwrap();
+    sp.stop_and_clear();
+
+    terminal.assert_screen("");
+    assert_eq!(terminal.cursor(), (0, 0));
+}
+
+#[test]
+fn prefix_and_suffix_are_left_out_of_the_final_line_as_needed() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output())
+        .prefix("[1/2]")
+        .suffix("(press q to quit)")
+        .start()
+        .unwrap();
+
+    terminal.assert_screen("[1/2] - Loading (press q to quit)");
+
+    sp.stop_with_failure();
+    terminal.assert_screen("[1/2] ✖ Loading");
+}
+
+#[test]
+fn paused_spinners_make_room_for_other_output() {
+    let clock = ManualClock::new();
+    let mut terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output()).start().unwrap();
+
+    sp.pause();
+    writeln!(terminal, "Found 3 files").unwrap();
+    sp.resume();
+    clock.advance(ms(100));
+
+    assert_eq!(terminal.lines(), ["Found 3 files", "\\ Loading"]);
+
+    sp.stop_with_success();
+    assert_eq!(terminal.lines(), ["Found 3 files", "✔ Loading"]);
+}
+
+#[test]
+fn spinners_one_after_another_keep_their_lines() {
+    let clock = ManualClock::new();
+    let mut terminal = TestTerminal::new();
+
+    for step in ["Resolving", "Compiling", "Linking"] {
+        let mut sp = builder(&clock, terminal.output())
+            .message(step)
+            .start()
+            .unwrap();
+        clock.advance(ms(150));
+        sp.stop_with_success();
+        writeln!(terminal).unwrap();
+    }
+
+    terminal.assert_screen("✔ Resolving\n✔ Compiling\n✔ Linking");
+}
+
+#[test]
+fn cursor_is_shown_again_once_stopped() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output())
+        .hide_cursor(true)
+        .start()
+        .unwrap();
+    assert!(!terminal.is_cursor_visible());
+
+    sp.pause();
+    assert!(terminal.is_cursor_visible());
+
+    sp.resume();
+    assert!(!terminal.is_cursor_visible());
+
+    sp.stop_with_success();
+    assert!(terminal.is_cursor_visible());
+}
+
+#[test]
+fn heartbeats_print_whole_lines() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output())
+        .mode(Mode::Heartbeat(30_000))
+        .start()
+        .unwrap();
+
+    clock.advance(ms(65_000));
+    sp.set_message("Linking");
+    sp.stop_with_success();
+
+    assert_eq!(
+        terminal.lines(),
+        [
+            "Loading",
+            "still running: Loading (30s)",
+            "still running: Loading (1m0s)",
+            "✔ Linking",
+        ]
+    );
+}
+
+#[test]
+fn accessible_mode_announces_changes_in_words() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output())
+        .mode(Mode::Accessible)
+        .start()
+        .unwrap();
+
+    clock.advance(ms(10_000));
+    sp.set_message("Loading");
+    sp.set_message("Saving");
+    sp.stop_with_failure();
+
+    assert_eq!(
+        terminal.lines(),
+        ["* Loading", "* Saving", "Saving: failed"]
+    );
+}
+
+#[test]
+#[should_panic(expected = "the screen doesn't match")]
+fn assert_screen_panics_on_a_mismatch() {
+    let mut terminal = TestTerminal::new();
+
+    write!(terminal, "Loading").unwrap();
+
+    terminal.assert_screen("Done");
+}
+
+#[test]
+fn templates_lay_out_the_running_and_final_lines() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output())
+        .suffix("(q to quit)")
+        .template("{message}: {frame} {suffix}")
+        .start()
+        .unwrap();
+
+    terminal.assert_screen("Loading: - (q to quit)");
+
+    sp.stop_with_success();
+    terminal.assert_screen("Loading: ✔");
+}
+
+#[test]
+fn colors_only_the_running_frame() {
+    let clock = ManualClock::new();
+    let terminal = TestTerminal::new();
+
+    let mut sp = builder(&clock, terminal.output())
+        .color(Color::Cyan)
+        .start()
+        .unwrap();
+
+    terminal.assert_screen("- Loading");
+    assert!(terminal.written().contains("\x1b[36m-\x1b[39m Loading"));
+
+    sp.stop_with_success();
+    assert!(terminal.written().ends_with("\r✔ Loading\x1b[K"));
+}